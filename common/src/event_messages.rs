@@ -17,4 +17,6 @@ pub enum EventMessage {
     RemeasureTextGroup(Uuid),
     /// Change the cursor icon
     SetCursorIcon(CursorIcon),
+    /// Move the keyboard focus to the Node bound to the given focus reference
+    FocusNode(Uuid),
 }
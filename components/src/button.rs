@@ -50,7 +50,6 @@ pub enum ButtonStatus {
 #[allow(non_snake_case)]
 pub fn Button<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element {
     let theme = use_get_theme(cx);
-    let status = use_state(cx, ButtonStatus::default);
 
     let onclick = move |ev| {
         if let Some(onclick) = &cx.props.onclick {
@@ -58,18 +57,8 @@ pub fn Button<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element {
         }
     };
 
-    let onmouseenter = move |_| {
-        status.set(ButtonStatus::Hovering);
-    };
-
-    let onmouseleave = move |_| {
-        status.set(ButtonStatus::default());
-    };
-
-    let background = match *status.get() {
-        ButtonStatus::Hovering => theme.button.hover_background,
-        ButtonStatus::Idle => theme.button.background,
-    };
+    let background = theme.button.background;
+    let hover_background = theme.button.hover_background;
     let color = theme.button.font_theme.color;
 
     render!(
@@ -80,8 +69,6 @@ pub fn Button<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element {
             padding: "2",
             container {
                 onclick: onclick,
-                onmouseenter: onmouseenter,
-                onmouseleave: onmouseleave,
                 width: "auto",
                 height: "auto",
                 direction: "both",
@@ -90,6 +77,7 @@ pub fn Button<'a>(cx: Scope<'a, ButtonProps<'a>>) -> Element {
                 radius: "5",
                 padding: "8",
                 background: "{background}",
+                hover_background: "{hover_background}",
                 &cx.props.children
             }
         }
@@ -87,6 +87,8 @@ pub fn DragZone<'a, T: 'static + Clone>(cx: Scope<'a, DragZoneProps<'a, T>>) ->
                 rect {
                     width: "0",
                     height: "0",
+                    // Let the cursor reach the drop zones under the dragged element
+                    pointer_events: "none",
                     scroll_x: "{pos.x}",
                     scroll_y: "{pos.y}",
                     &cx.props.drag_element
@@ -121,7 +123,8 @@ pub struct DropZoneProps<'a, T> {
 pub fn DropZone<'a, T: 'static + Clone>(cx: Scope<'a, DropZoneProps<'a, T>>) -> Element<'a> {
     let drags = use_shared_state::<Option<T>>(cx);
 
-    let onclick = move |_: MouseEvent| {
+    // The press happened in the DragZone, so the drop only causes a `mouseup` and not a `click`
+    let onmouseup = move |_: MouseEvent| {
         if let Some(drags) = drags {
             if let Some(current_drags) = &*drags.read() {
                 cx.props.ondrop.call(current_drags.clone());
@@ -134,7 +137,7 @@ pub fn DropZone<'a, T: 'static + Clone>(cx: Scope<'a, DropZoneProps<'a, T>>) ->
 
     render!(
         rect {
-            onclick: onclick,
+            onmouseup: onmouseup,
             &cx.props.children
         }
     )
@@ -226,7 +229,7 @@ mod test {
         );
 
         utils.push_event(FreyaEvent::Mouse {
-            name: "click".to_string(),
+            name: "mouseup".to_string(),
             cursor: (5.0, 300.0).into(),
             button: Some(MouseButton::Left),
         });
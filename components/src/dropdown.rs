@@ -31,12 +31,11 @@ where
 {
     let selected = use_shared_state::<T>(cx).unwrap();
     let theme = use_get_theme(cx);
-    let is_hovering = use_state(cx, || false);
 
     let is_selected = *selected.read() == cx.props.value;
     let dropdown_item_theme = &theme.dropdown_item;
 
-    let background = if is_selected || *is_hovering.get() {
+    let background = if is_selected {
         dropdown_item_theme.hover_background
     } else {
         dropdown_item_theme.background
@@ -47,16 +46,9 @@ where
         width: "100%",
         height: "35",
         background: background,
+        hover_background: dropdown_item_theme.hover_background,
         padding: "6",
         radius: "3",
-        onmouseover: move |_| {
-            if !*is_hovering.get() {
-                is_hovering.set(true);
-            }
-        },
-        onmouseleave: move |_| {
-            is_hovering.set(false);
-        },
         onclick: move |_| {
             if let Some(onclick) = &cx.props.onclick {
                 onclick.call(());
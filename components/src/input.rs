@@ -1,9 +1,10 @@
 use crate::CursorArea;
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
-use freya_elements::events::{KeyboardData, MouseEvent};
+use freya_elements::events::{CompositionEvent, KeyboardData, MouseEvent, TextInputEvent};
 use freya_hooks::{
-    use_editable, use_focus, use_get_theme, EditableConfig, EditableEvent, EditableMode, TextEditor,
+    use_editable, use_get_theme, use_node_focus, EditableConfig, EditableEvent, EditableMode,
+    TextEditor,
 };
 use winit::window::CursorIcon;
 
@@ -29,7 +30,6 @@ pub struct InputProps<'a> {
 /// ```rust
 /// # use freya::prelude::*;
 /// fn app(cx: Scope) -> Element {
-///     use_init_focus(cx);
 ///     let value = use_state(cx, String::new);
 ///
 ///     render!(
@@ -53,7 +53,8 @@ pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element {
         EditableMode::MultipleLinesSingleEditor,
     );
     let theme = use_get_theme(cx);
-    let (focused, focus) = use_focus(cx);
+    let focus = use_node_focus(cx);
+    let focused = focus.is_focused();
 
     let text = &cx.props.value;
     let button_theme = &theme.button;
@@ -82,6 +83,34 @@ pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element {
         }
     };
 
+    let oncomposition = {
+        to_owned![editable];
+        move |e: CompositionEvent| {
+            if focused {
+                editable.process_event(&EditableEvent::Composition(e.data));
+            }
+        }
+    };
+
+    let oncompositionend = {
+        to_owned![editable];
+        move |_: CompositionEvent| {
+            editable.process_event(&EditableEvent::CompositionEnd);
+        }
+    };
+
+    let ontextinput = {
+        to_owned![editable];
+        move |e: TextInputEvent| {
+            if focused {
+                editable.process_event(&EditableEvent::TextInput(e.data));
+                cx.props
+                    .onchange
+                    .call(editable.editor().current().to_string());
+            }
+        }
+    };
+
     let onmousedown = {
         to_owned![editable];
         move |e: MouseEvent| {
@@ -103,8 +132,34 @@ pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element {
         }
     };
 
+    let editor = editable.editor().get();
+
+    // Text being composed by an input method is underlined until it's committed
+    let text_spans = if let Some((before, preedit, after)) = editor.preedit_spans() {
+        rsx!(
+            text {
+                "{before}"
+            }
+            text {
+                decoration: "underline",
+                "{preedit}"
+            }
+            text {
+                "{after}"
+            }
+        )
+    } else {
+        rsx!(
+            text {
+                "{text}"
+            }
+        )
+    };
+
+    // The cursor goes after the text being composed
     let cursor_char = if focused {
-        editable.editor().cursor_pos().to_string()
+        let preedit_len = editor.preedit().map(|preedit| preedit.chars().count());
+        (editor.cursor_pos() + preedit_len.unwrap_or_default()).to_string()
     } else {
         "none".to_string()
     };
@@ -114,9 +169,11 @@ pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element {
             icon: CursorIcon::Text,
             container {
                 onkeydown: onkeydown,
-                onclick: move |_| {
-                    focus();
-                },
+                oncompositionstart: oncomposition.clone(),
+                oncompositionupdate: oncomposition,
+                oncompositionend: oncompositionend,
+                ontextinput: ontextinput,
+                focus_reference: focus.attribute(cx),
                 width: "auto",
                 height: "auto",
                 direction: "both",
@@ -143,9 +200,7 @@ pub fn Input<'a>(cx: Scope<'a, InputProps<'a>>) -> Element {
                         onmouseover: onmouseover,
                         onmousedown: onmousedown,
                         highlights: highlights_attr,
-                        text {
-                            "{text}"
-                        }
+                        text_spans
                     }
                 }
             }
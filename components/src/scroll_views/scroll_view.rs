@@ -99,6 +99,9 @@ pub fn ScrollView<'a>(cx: Scope<'a, ScrollViewProps<'a>>) -> Element {
 
     // Moves the Y axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // Scroll views around this one won't scroll
+        e.data.stop_propagation();
+
         if !*clicking_shift.read() {
             let wheel_y = e.get_delta_y();
 
@@ -140,6 +140,9 @@ pub fn VirtualScrollView<'a, T>(cx: Scope<'a, VirtualScrollViewProps<'a, T>>) ->
 
     // Moves the Y axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // Scroll views around this one won't scroll
+        e.data.stop_propagation();
+
         if !*clicking_shift.read() {
             let wheel_y = e.get_delta_y();
 
@@ -100,6 +100,9 @@ pub fn Slider<'a>(cx: Scope<'a, SliderProps>) -> Element<'a> {
     };
 
     let onwheel = move |e: WheelEvent| {
+        // Don't scroll the scroll view that contains this slider
+        e.data.stop_propagation();
+
         let wheel_y = e.get_delta_y();
         let progress_x = (value / 100.0) * cx.props.width;
 
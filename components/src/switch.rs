@@ -42,25 +42,8 @@ pub struct SwitchProps<'a> {
 pub fn Switch<'a>(cx: Scope<'a, SwitchProps<'a>>) -> Element<'a> {
     let animation = use_animation(cx, 0.0);
     let theme = use_get_theme(cx);
-    let hovering = use_state(cx, || false);
-    let clicking = use_state(cx, || false);
-
-    let onmouseleave = |_: MouseEvent| {
-        if !(*clicking.get()) {
-            hovering.set(false);
-        }
-    };
-
-    let onmouseover = |_: MouseEvent| {
-        hovering.set(true);
-    };
-
-    let onmousedown = |_: MouseEvent| {
-        clicking.set(true);
-    };
 
     let onclick = |_: MouseEvent| {
-        clicking.set(false);
         cx.props.ontoggled.call(());
     };
 
@@ -101,9 +84,6 @@ pub fn Switch<'a>(cx: Scope<'a, SwitchProps<'a>>) -> Element<'a> {
                 padding: "1",
                 radius: "50",
                 background: "{border}",
-                onmousedown: onmousedown,
-                onmouseover: onmouseover,
-                onmouseleave: onmouseleave,
                 onclick: onclick,
                 rect {
                     width: "100%",
@@ -3,7 +3,8 @@ use std::{any::Any, rc::Rc};
 use dioxus_core::ElementId;
 use dioxus_native_core::NodeId;
 use freya_elements::events::{
-    pointer::PointerType, KeyboardData, MouseData, PointerData, TouchData, WheelData,
+    pointer::PointerType, CompositionData, EventPropagation, FileData, FocusData, KeyboardData,
+    MouseData, PointerData, TextInputData, TouchData, WheelData,
 };
 use torin::prelude::*;
 
@@ -23,6 +24,18 @@ impl DomEvent {
         return does_event_move_cursor(self.name.as_str());
     }
 
+    /// Check if a previous listener of this event stopped its propagation,
+    /// in which case it must not be emitted to its listener.
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.data.propagation().is_stopped()
+    }
+
+    /// Share the [`EventPropagation`] with other listeners of the same event.
+    pub fn with_propagation(mut self, propagation: EventPropagation) -> Self {
+        self.data = self.data.with_propagation(propagation);
+        self
+    }
+
     pub fn from_freya_event(
         node_id: NodeId,
         element_id: ElementId,
@@ -118,6 +131,38 @@ impl DomEvent {
                     data: event_data,
                 }
             }
+            FreyaEvent::Composition { data, cursor, .. } => Self {
+                node_id,
+                element_id,
+                name: event_name,
+                data: DomEventData::Composition(CompositionData::new(data.clone(), *cursor)),
+            },
+            FreyaEvent::TextInput { text, .. } => Self {
+                node_id,
+                element_id,
+                name: event_name,
+                data: DomEventData::TextInput(TextInputData::new(text.clone())),
+            },
+            FreyaEvent::File {
+                cursor, file_path, ..
+            } => {
+                let screen_coordinates = *cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    element_id,
+                    name: event_name,
+                    data: DomEventData::File(FileData::new(
+                        screen_coordinates,
+                        (element_x, element_y).into(),
+                        file_path.clone(),
+                    )),
+                }
+            }
         }
     }
 }
@@ -130,9 +175,43 @@ pub enum DomEventData {
     Wheel(WheelData),
     Touch(TouchData),
     Pointer(PointerData),
+    Focus(FocusData),
+    Composition(CompositionData),
+    TextInput(TextInputData),
+    File(FileData),
 }
 
 impl DomEventData {
+    pub fn propagation(&self) -> &EventPropagation {
+        match self {
+            DomEventData::Mouse(m) => m.get_propagation(),
+            DomEventData::Keyboard(k) => k.get_propagation(),
+            DomEventData::Wheel(w) => w.get_propagation(),
+            DomEventData::Touch(t) => t.get_propagation(),
+            DomEventData::Pointer(p) => p.get_propagation(),
+            DomEventData::Focus(f) => f.get_propagation(),
+            DomEventData::Composition(c) => c.get_propagation(),
+            DomEventData::TextInput(t) => t.get_propagation(),
+            DomEventData::File(f) => f.get_propagation(),
+        }
+    }
+
+    pub fn with_propagation(self, propagation: EventPropagation) -> Self {
+        match self {
+            DomEventData::Mouse(m) => DomEventData::Mouse(m.with_propagation(propagation)),
+            DomEventData::Keyboard(k) => DomEventData::Keyboard(k.with_propagation(propagation)),
+            DomEventData::Wheel(w) => DomEventData::Wheel(w.with_propagation(propagation)),
+            DomEventData::Touch(t) => DomEventData::Touch(t.with_propagation(propagation)),
+            DomEventData::Pointer(p) => DomEventData::Pointer(p.with_propagation(propagation)),
+            DomEventData::Focus(f) => DomEventData::Focus(f.with_propagation(propagation)),
+            DomEventData::Composition(c) => {
+                DomEventData::Composition(c.with_propagation(propagation))
+            }
+            DomEventData::TextInput(t) => DomEventData::TextInput(t.with_propagation(propagation)),
+            DomEventData::File(f) => DomEventData::File(f.with_propagation(propagation)),
+        }
+    }
+
     pub fn any(self) -> Rc<dyn Any> {
         match self {
             DomEventData::Mouse(m) => Rc::new(m),
@@ -140,10 +219,23 @@ impl DomEventData {
             DomEventData::Wheel(w) => Rc::new(w),
             DomEventData::Touch(t) => Rc::new(t),
             DomEventData::Pointer(p) => Rc::new(p),
+            DomEventData::Focus(f) => Rc::new(f),
+            DomEventData::Composition(c) => Rc::new(c),
+            DomEventData::TextInput(t) => Rc::new(t),
+            DomEventData::File(f) => Rc::new(f),
         }
     }
 }
 
+/// Check if an event goes through the capture and bubble phases,
+/// instead of only being emitted to the elements where it happens.
+pub fn does_event_bubble(event_name: &str) -> bool {
+    !matches!(
+        event_name,
+        "mouseenter" | "mouseleave" | "pointerenter" | "pointerleave" | "focus" | "blur"
+    )
+}
+
 pub fn does_event_move_cursor(event_name: &str) -> bool {
     ["pointerover", "pointerenter", "mouseover", "mouseenter"].contains(&event_name)
 }
@@ -3,15 +3,21 @@ use dioxus_native_core::real_dom::NodeImmutable;
 use dioxus_native_core::NodeId;
 use freya_dom::prelude::FreyaDOM;
 use freya_layout::Layers;
+use freya_node_state::{PointerEvents, PointerEventsState};
 
-use freya_node_state::Style;
-use rustc_hash::FxHashMap;
-use skia_safe::Color;
+use freya_elements::events::keyboard::Key;
+use freya_elements::events::EventPropagation;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 pub use crate::dom_events::DomEvent;
+use crate::dom_events::{does_event_bubble, DomEventData};
 pub use crate::events_processor::EventsProcessor;
 pub use crate::freya_events::FreyaEvent;
 
+use crate::focus::{measure_focus, set_focus};
+use crate::hit_test::{hit_test, AnimatedTransforms, NodesMatrices};
+use crate::interaction_states::measure_interaction_states;
+
 use crate::{EventEmitter, EventsQueue, NodesEvents, ViewportsCollection};
 
 /// Measure globale events
@@ -19,7 +25,7 @@ pub fn measure_global_events(events: &EventsQueue) -> Vec<FreyaEvent> {
     let mut global_events = Vec::default();
     for event in events {
         let event_name = match event.get_name() {
-            "click" => Some("globalclick"),
+            "click" | "mouseup" => Some("globalclick"),
             "mouseover" => Some("globalmouseover"),
             _ => None,
         };
@@ -39,52 +45,57 @@ pub fn measure_potential_event_listeners(
     events: &EventsQueue,
     viewports_collection: &ViewportsCollection,
     fdom: &FreyaDOM,
+    animated_transforms: &AnimatedTransforms,
 ) -> NodesEvents {
     let mut potential_events = FxHashMap::default();
+    let mut nodes_matrices = NodesMatrices::new(fdom, animated_transforms);
 
     let layout = fdom.layout();
+    let rdom = fdom.rdom();
 
     // Propagate events from the top to the bottom
     for layer_num in layers_nums {
         let layer = layers.layers.get(layer_num).unwrap();
 
         for node_id in layer {
-            let areas = layout.get(*node_id);
-            if let Some(areas) = areas {
-                'events: for event in events.iter() {
-                    if let FreyaEvent::Keyboard { name, .. } = event {
+            if layout.get(*node_id).is_some() {
+                // Elements with `pointer_events: none` let the pointer through to the ones underneath
+                let pointer_events = rdom
+                    .get(*node_id)
+                    .and_then(|node| {
+                        node.get::<PointerEventsState>()
+                            .map(|state| state.pointer_events)
+                    })
+                    .unwrap_or_default();
+
+                for event in events.iter() {
+                    if event.targets_focus() {
                         let event_data = (*node_id, event.clone());
                         potential_events
-                            .entry(name.clone())
-                            .or_insert_with(|| vec![event_data.clone()])
+                            .entry(event.get_name().to_string())
+                            .or_insert_with(Vec::new)
                             .push(event_data);
                     } else {
                         let data = match event {
                             FreyaEvent::Mouse { name, cursor, .. } => Some((name, cursor)),
                             FreyaEvent::Wheel { name, cursor, .. } => Some((name, cursor)),
                             FreyaEvent::Touch { name, location, .. } => Some((name, location)),
+                            FreyaEvent::File { name, cursor, .. } => Some((name, cursor)),
                             _ => None,
                         };
                         if let Some((name, cursor)) = data {
-                            let cursor_is_inside = areas.area.contains(cursor.to_f32());
-
-                            // Make sure the cursor is inside the node area
-                            if cursor_is_inside {
-                                let viewports = viewports_collection.get(node_id);
-
-                                // Make sure the cursor is inside all the applicable viewports from the element
-                                if let Some((_, viewports)) = viewports {
-                                    for viewport_id in viewports {
-                                        let viewport =
-                                            viewports_collection.get(viewport_id).unwrap().0;
-                                        if let Some(viewport) = viewport {
-                                            if !viewport.contains(cursor.to_f32()) {
-                                                continue 'events;
-                                            }
-                                        }
-                                    }
-                                }
+                            if pointer_events == PointerEvents::None {
+                                continue;
+                            }
 
+                            // Make sure the cursor hits the painted parts of the node
+                            if hit_test(
+                                *node_id,
+                                *cursor,
+                                fdom,
+                                viewports_collection,
+                                &mut nodes_matrices,
+                            ) {
                                 let event_data = (*node_id, event.clone());
 
                                 potential_events
@@ -104,16 +115,20 @@ pub fn measure_potential_event_listeners(
 
 /// Some events might cause other events, like for example:
 /// A `mouseover` might also trigger a `mouseenter`
-/// A `mousedown` or a `touchdown` might also trigger a `pointerdown`
+/// A `mousedown` or a `touchstart` might also trigger a `pointerdown`
+/// A `mouseup` or a `touchend` might also trigger a `pointerup`
 fn get_derivated_events(event_name: &str) -> Vec<&str> {
     match event_name {
         "mouseover" => {
             vec![event_name, "mouseenter", "pointerenter", "pointerover"]
         }
-        "mousedown" | "touchdown" => {
+        "touchmove" => {
+            vec![event_name, "pointerover"]
+        }
+        "mousedown" | "touchstart" => {
             vec![event_name, "pointerdown"]
         }
-        "click" | "ontouchend" => {
+        "mouseup" | "touchend" => {
             vec![event_name, "pointerup"]
         }
         "mouseleave" => {
@@ -123,86 +138,227 @@ fn get_derivated_events(event_name: &str) -> Vec<&str> {
     }
 }
 
-const STACKED_EVENTS: [&str; 13] = [
-    "mouseover",
-    "mouseenter",
-    "mouseleave",
-    "click",
-    "keydown",
-    "keyup",
-    "touchcancel",
-    "touchend",
-    "touchend",
-    "touchstart",
-    "pointerover",
-    "pointerenter",
-    "pointerleave",
-];
-
-const FIRST_CAPTURED_EVENTS: [&str; 1] = ["wheel"];
-
-const LAST_CAPTURED_EVENTS: [&str; 3] = ["click", "touchstart", "touchend"];
-
-/// Measure what DOM events could be emited
-fn measure_dom_events(
+/// Get the ancestors of an element and the element itself, from the root to the element.
+fn event_path(node_id: NodeId, fdom: &FreyaDOM) -> Vec<NodeId> {
+    let rdom = fdom.rdom();
+    let mut path = Vec::new();
+
+    let mut node_id = Some(node_id);
+    while let Some(id) = node_id {
+        path.push(id);
+        node_id = rdom.get(id).and_then(|node| node.parent_id());
+    }
+
+    path.reverse();
+    path
+}
+
+/// Send the pointer moves and releases to the element capturing the pointer,
+/// no matter where they happen. They bubble from that element as usual.
+fn apply_pointer_capture(
+    events: &EventsQueue,
     potential_events: &mut NodesEvents,
+    captured_node: NodeId,
     fdom: &FreyaDOM,
+) {
+    // Elements are sorted from the bottom to the top, so the captured element is the target
+    let path = event_path(captured_node, fdom);
+
+    for event in events {
+        let event_name = event.get_name();
+        let is_captured = matches!(event, FreyaEvent::Mouse { .. } | FreyaEvent::Touch { .. })
+            && matches!(
+                event_name,
+                "mouseover" | "mouseup" | "click" | "touchmove" | "touchend" | "touchcancel"
+            );
+        if is_captured {
+            potential_events.insert(
+                event_name.to_string(),
+                path.iter()
+                    .map(|node_id| (*node_id, event.clone()))
+                    .collect(),
+            );
+        }
+    }
+}
+
+/// Create the DOM event of an element.
+fn to_dom_event(
+    node_id: NodeId,
+    event_name: &str,
+    request: &FreyaEvent,
+    fdom: &FreyaDOM,
+    scale_factor: f64,
+) -> Option<DomEvent> {
+    let area = fdom.layout().get(node_id)?.area;
+    let element_id = fdom.rdom().get(node_id)?.mounted_id()?;
+
+    let mut request = request.clone();
+    request.set_name(event_name.to_string());
+
+    Some(DomEvent::from_freya_event(
+        node_id,
+        element_id,
+        &request,
+        Some(area),
+        scale_factor,
+    ))
+}
+
+/// Create the DOM events of an event that targets the given element,
+/// going through the capture phase from the root to the element and then bubbling back to the root.
+fn propagate_dom_event(
+    target_id: NodeId,
+    event_name: &str,
+    request: &FreyaEvent,
+    event_listeners: &FxHashSet<NodeId>,
+    capture_listeners: &FxHashSet<NodeId>,
+    fdom: &FreyaDOM,
+    scale_factor: f64,
+) -> Vec<DomEvent> {
+    let path = event_path(target_id, fdom);
+    let propagation = EventPropagation::default();
+    let capture_event_name = format!("{event_name}capture");
+
+    let capture_phase = path
+        .iter()
+        .filter(|node_id| capture_listeners.contains(node_id))
+        .map(|node_id| (node_id, capture_event_name.as_str()));
+    let bubble_phase = path
+        .iter()
+        .rev()
+        .filter(|node_id| event_listeners.contains(node_id))
+        .map(|node_id| (node_id, event_name));
+
+    capture_phase
+        .chain(bubble_phase)
+        .filter_map(|(node_id, name)| to_dom_event(*node_id, name, request, fdom, scale_factor))
+        .map(|event| event.with_propagation(propagation.clone()))
+        .collect()
+}
+
+/// Measure what DOM events could be emited.
+///
+/// Events that bubble are dispatched to the element on top of the others,
+/// after a capture phase that goes from the root to that element, and then bubble back to the root.
+/// Keyboard and text input events are dispatched the same way to the focused element.
+/// Events like `mouseenter` that don't bubble are emitted to all the elements where they happen.
+fn measure_dom_events(
+    potential_events: &NodesEvents,
+    fdom: &FreyaDOM,
+    focused_node: Option<NodeId>,
     scale_factor: f64,
 ) -> Vec<DomEvent> {
     let mut new_events = Vec::new();
     let rdom = fdom.rdom();
 
-    for (event_name, event_nodes) in potential_events.iter_mut() {
-        let derivated_events = get_derivated_events(event_name.as_str());
-
-        let mut found_nodes: Vec<(&NodeId, FreyaEvent)> = Vec::new();
-        for derivated_event_name in derivated_events {
-            let listeners = rdom.get_listening_sorted(derivated_event_name);
-            'event_nodes: for (node_id, request) in event_nodes.iter() {
-                for listener in &listeners {
-                    if listener.id() == *node_id {
-                        let Style { background, .. } = &*listener.get::<Style>().unwrap();
-
-                        let mut request = request.clone();
-                        request.set_name(derivated_event_name.to_string());
-
-                        // Stop searching on first match
-                        if background != &Color::TRANSPARENT
-                            && FIRST_CAPTURED_EVENTS.contains(&derivated_event_name)
-                        {
-                            break 'event_nodes;
-                        }
-
-                        // Only keep the last matched event
-                        if background != &Color::TRANSPARENT
-                            && LAST_CAPTURED_EVENTS.contains(&derivated_event_name)
-                        {
-                            found_nodes.clear();
+    let listeners = |event_name: &str| -> FxHashSet<NodeId> {
+        rdom.get_listening_sorted(event_name)
+            .iter()
+            .map(|listener| listener.id())
+            .collect()
+    };
+
+    for (event_name, event_nodes) in potential_events {
+        for derivated_event_name in get_derivated_events(event_name) {
+            let event_listeners = listeners(derivated_event_name);
+            let capture_event_name = format!("{derivated_event_name}capture");
+            let capture_listeners = listeners(&capture_event_name);
+
+            let focus_event = event_nodes
+                .first()
+                .filter(|(_, request)| request.targets_focus());
+            if let Some((first_node_id, _)) = focus_event {
+                let requests = event_nodes
+                    .iter()
+                    .filter(|(node_id, _)| node_id == first_node_id);
+                for (_, request) in requests {
+                    if let Some(focused_node) = focused_node {
+                        // Keyboard and text input events target the focused element
+                        new_events.extend(propagate_dom_event(
+                            focused_node,
+                            derivated_event_name,
+                            request,
+                            &event_listeners,
+                            &capture_listeners,
+                            fdom,
+                            scale_factor,
+                        ));
+                    } else {
+                        // Without a focused element they are emitted to all their listeners
+                        for listener in rdom.get_listening_sorted(derivated_event_name) {
+                            new_events.extend(to_dom_event(
+                                listener.id(),
+                                derivated_event_name,
+                                request,
+                                fdom,
+                                scale_factor,
+                            ));
                         }
+                    }
+                }
+                continue;
+            }
 
-                        // Stack the matched events
-                        if STACKED_EVENTS.contains(&derivated_event_name) {
-                            found_nodes.push((node_id, request))
-                        } else {
-                            found_nodes = vec![(node_id, request)]
-                        }
+            if !does_event_bubble(derivated_event_name) {
+                for (node_id, request) in event_nodes {
+                    if event_listeners.contains(node_id) {
+                        new_events.extend(to_dom_event(
+                            *node_id,
+                            derivated_event_name,
+                            request,
+                            fdom,
+                            scale_factor,
+                        ));
                     }
                 }
+                continue;
+            }
+
+            // Elements are sorted from the bottom to the top, the one on top is the target
+            // even if it doesn't listen to the event, its ancestors might
+            let target = event_nodes.last();
+
+            if let Some((target_id, request)) = target {
+                new_events.extend(propagate_dom_event(
+                    *target_id,
+                    derivated_event_name,
+                    request,
+                    &event_listeners,
+                    &capture_listeners,
+                    fdom,
+                    scale_factor,
+                ));
             }
         }
+    }
 
-        for (node_id, request_event) in found_nodes {
-            let areas = fdom.layout().get(*node_id).unwrap().clone();
-            let node_ref = fdom.rdom().get(*node_id).unwrap();
-            let element_id = node_ref.mounted_id().unwrap();
-            let event = DomEvent::from_freya_event(
-                *node_id,
-                element_id,
-                &request_event,
-                Some(areas.area),
-                scale_factor,
-            );
-            new_events.push(event);
+    new_events
+}
+
+/// Measure the DOM events that are only emitted to some specific elements, like `mouseleave`.
+fn measure_colateral_dom_events(
+    colateral_events: &NodesEvents,
+    fdom: &FreyaDOM,
+    scale_factor: f64,
+) -> Vec<DomEvent> {
+    let mut new_events = Vec::new();
+    let rdom = fdom.rdom();
+
+    for (event_name, event_nodes) in colateral_events {
+        for derivated_event_name in get_derivated_events(event_name) {
+            let listeners = rdom.get_listening_sorted(derivated_event_name);
+            for (node_id, request) in event_nodes {
+                if listeners.iter().any(|listener| listener.id() == *node_id) {
+                    new_events.extend(to_dom_event(
+                        *node_id,
+                        derivated_event_name,
+                        request,
+                        fdom,
+                        scale_factor,
+                    ));
+                }
+            }
         }
     }
 
@@ -234,7 +390,25 @@ fn emit_global_events_listeners(
     }
 }
 
-/// Process the events and emit them to the DOM
+/// Get the propagations of the events whose default action is moving the focus,
+/// like pressing an element or the `Tab` key.
+fn focus_propagations(events: &[DomEvent]) -> Vec<EventPropagation> {
+    events
+        .iter()
+        .filter(|event| {
+            let name = event.name.trim_end_matches("capture");
+            match &event.data {
+                DomEventData::Keyboard(data) => name == "keydown" && data.key == Key::Tab,
+                _ => name == "mousedown" || name == "touchstart",
+            }
+        })
+        .map(|event| event.data.propagation().clone())
+        .collect()
+}
+
+/// Process the events and emit them to the DOM,
+/// once their listeners ran [`apply_pending_focus`](crate::focus::apply_pending_focus) must be called.
+/// Returns `true` if some elements must be repainted because their interaction state changed.
 pub fn process_events(
     dom: &FreyaDOM,
     layers: &Layers,
@@ -243,7 +417,7 @@ pub fn process_events(
     events_processor: &mut EventsProcessor,
     viewports_collection: &ViewportsCollection,
     scale_factor: f64,
-) {
+) -> bool {
     let mut layers_nums: Vec<&i16> = layers.layers.keys().collect();
 
     // Order the layers from top to bottom
@@ -251,22 +425,108 @@ pub fn process_events(
 
     let global_events = measure_global_events(events);
 
-    let mut potential_events =
-        measure_potential_event_listeners(&layers_nums, layers, events, viewports_collection, dom);
-
-    let emitted_events = measure_dom_events(&mut potential_events, dom, scale_factor);
-
-    let mut potential_colateral_events =
-        events_processor.process_events(emitted_events, events, event_emitter);
+    let mut potential_events = measure_potential_event_listeners(
+        &layers_nums,
+        layers,
+        events,
+        viewports_collection,
+        dom,
+        events_processor.animated_transforms(),
+    );
+
+    // The cursor leaving the window is turned into leave events by the EventsProcessor
+    potential_events.remove("mouseleave");
+    // And so are the dragged files
+    potential_events.remove("filehovercancel");
+
+    let pointer_capture = events_processor.pointer_capture();
+    if let Some(captured_node) = pointer_capture {
+        apply_pointer_capture(events, &mut potential_events, captured_node, dom);
+    }
 
-    let emitted_colateral_events =
-        measure_dom_events(&mut potential_colateral_events, dom, scale_factor);
+    // Releases that happen where the press did also click the elements
+    let click_events = events_processor.measure_click_events(events, &potential_events);
+
+    // Canceling the dragged files is emitted to the elements they were dragged over
+    let file_hover_cancel_events =
+        events_processor.measure_file_hover_cancel_events(events, &potential_events);
+
+    let mut must_repaint = measure_interaction_states(
+        events,
+        &potential_events,
+        dom,
+        events_processor.interaction_states_mut(),
+    );
+
+    let focused_node = events_processor.focused_node();
+
+    // The focus moves after the events are emitted to the element that had it
+    let new_focus = measure_focus(events, &potential_events, dom, focused_node);
+
+    let mut emitted_events = measure_dom_events(&potential_events, dom, focused_node, scale_factor);
+
+    // Clicks are emitted after the releases that caused them
+    emitted_events.extend(measure_dom_events(
+        &click_events,
+        dom,
+        focused_node,
+        scale_factor,
+    ));
+
+    let focus_propagations = focus_propagations(&emitted_events);
+
+    // Pointer events can capture the pointer
+    let emitted_events = emitted_events
+        .into_iter()
+        .map(|event| events_processor.with_pointer_capture(event))
+        .collect();
+
+    // Elements under the cursor
+    let hovered_nodes = potential_events
+        .get("mouseover")
+        .into_iter()
+        .flatten()
+        .map(|(node_id, _)| *node_id)
+        .collect::<Vec<NodeId>>();
+
+    let colateral_events =
+        events_processor.process_events(emitted_events, events, &hovered_nodes, event_emitter);
+
+    let mut emitted_colateral_events =
+        measure_colateral_dom_events(&colateral_events, dom, scale_factor);
+    emitted_colateral_events.extend(measure_colateral_dom_events(
+        &file_hover_cancel_events,
+        dom,
+        scale_factor,
+    ));
 
     for event in emitted_colateral_events {
         event_emitter.send(event).unwrap();
     }
 
+    // Listeners can prevent the focus from moving, so it waits for them if there are any
+    if let Some(new_focus) = new_focus {
+        if focus_propagations.is_empty() {
+            must_repaint |= set_focus(new_focus, dom, events_processor, event_emitter);
+        } else {
+            events_processor.set_pending_focus(new_focus, focus_propagations);
+        }
+    }
+
     emit_global_events_listeners(global_events, dom, event_emitter, scale_factor);
 
+    // Releasing the pointer also releases its capture
+    let is_pointer_released = events.iter().any(|event| {
+        matches!(
+            event.get_name(),
+            "mouseup" | "click" | "touchend" | "touchcancel"
+        )
+    });
+    if pointer_capture.is_some() && is_pointer_released {
+        events_processor.release_pointer_capture();
+    }
+
     events.clear();
+
+    must_repaint
 }
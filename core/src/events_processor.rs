@@ -1,12 +1,24 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
 use dioxus_native_core::NodeId;
+use freya_dom::prelude::FreyaDOM;
+use freya_elements::events::mouse::MouseButton;
+use freya_elements::events::pointer::PointerCapture;
+use freya_elements::events::EventPropagation;
 use rustc_hash::FxHashMap;
 
 use crate::{
-    dom_events::{does_event_move_cursor, DomEvent},
+    dom_events::{does_event_move_cursor, DomEvent, DomEventData},
     freya_events::FreyaEvent,
-    EventEmitter,
+    hit_test::AnimatedTransforms,
+    interaction_states::InteractionStates,
+    EventEmitter, EventsQueue, NodesEvents,
 };
 
+/// Default max time between two clicks for them to be a double click.
+pub const DEFAULT_DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
 /// State of an element.
 #[derive(Default)]
 struct ElementState {
@@ -14,92 +26,340 @@ struct ElementState {
 }
 
 /// [`EventsProcessor`] stores the elements events states.
-///
-/// TODO(marc2332): Remove deleted Elements
-#[derive(Default)]
 pub struct EventsProcessor {
     states: FxHashMap<NodeId, ElementState>,
+    interaction_states: InteractionStates,
+    focused_node: Option<NodeId>,
+    /// Focus that moves once the listeners of the events that caused it ran, unless they prevent it.
+    pending_focus: Option<(Option<NodeId>, Vec<EventPropagation>)>,
+    /// Elements that were under the cursor when each button was pressed.
+    pressed_nodes: FxHashMap<MouseButton, Vec<NodeId>>,
+    /// Elements that were under the dragged files, they receive the `filehovercancel`.
+    file_hovered_nodes: Vec<NodeId>,
+    /// When the last click happened and the elements it clicked, used to detect double clicks.
+    last_click: Option<(Instant, Vec<NodeId>)>,
+    double_click_interval: Duration,
+    /// Element capturing the pointer, shared with the pointer events so their listeners can change it.
+    pointer_capture: Arc<Mutex<Option<NodeId>>>,
+    /// Transformations being animated, the pointer events hit the elements where they are displayed.
+    animated_transforms: AnimatedTransforms,
+}
+
+impl Default for EventsProcessor {
+    fn default() -> Self {
+        Self {
+            states: FxHashMap::default(),
+            interaction_states: InteractionStates::default(),
+            focused_node: None,
+            pending_focus: None,
+            pressed_nodes: FxHashMap::default(),
+            file_hovered_nodes: Vec::new(),
+            last_click: None,
+            double_click_interval: DEFAULT_DOUBLE_CLICK_INTERVAL,
+            pointer_capture: Arc::default(),
+            animated_transforms: AnimatedTransforms::default(),
+        }
+    }
 }
 
 impl EventsProcessor {
+    /// Specify the max time between two clicks for them to be a double click.
+    pub fn with_double_click_interval(mut self, double_click_interval: Duration) -> Self {
+        self.double_click_interval = double_click_interval;
+        self
+    }
+
+    /// Get the [`InteractionStates`] of the elements with state-scoped styles.
+    pub fn interaction_states(&self) -> &InteractionStates {
+        &self.interaction_states
+    }
+
+    pub(crate) fn interaction_states_mut(&mut self) -> &mut InteractionStates {
+        &mut self.interaction_states
+    }
+
+    /// Forget the states of the elements that were removed from the DOM,
+    /// so they don't receive events like `mouseleave` anymore.
+    pub fn prune_removed_nodes(&mut self, fdom: &FreyaDOM) {
+        let rdom = fdom.rdom();
+        let exists = |node_id: &NodeId| rdom.get(*node_id).is_some();
+
+        self.states.retain(|node_id, _| exists(node_id));
+        self.interaction_states.retain(exists);
+
+        if !self.focused_node.iter().all(exists) {
+            self.set_focused_node(None);
+        }
+
+        if !self.pointer_capture().iter().all(exists) {
+            self.release_pointer_capture();
+        }
+
+        for pressed_nodes in self.pressed_nodes.values_mut() {
+            pressed_nodes.retain(exists);
+        }
+
+        self.file_hovered_nodes.retain(exists);
+
+        if let Some((_, last_clicked_nodes)) = &mut self.last_click {
+            last_clicked_nodes.retain(exists);
+        }
+    }
+
+    /// Get the element that has the keyboard focus.
+    pub fn focused_node(&self) -> Option<NodeId> {
+        self.focused_node
+    }
+
+    pub(crate) fn set_focused_node(&mut self, focused_node: Option<NodeId>) {
+        self.focused_node = focused_node;
+        let focused = focused_node.into_iter().collect::<Vec<_>>();
+        self.interaction_states
+            .set_only(&focused, |state| &mut state.focused);
+    }
+
+    pub(crate) fn set_pending_focus(
+        &mut self,
+        new_focus: Option<NodeId>,
+        propagations: Vec<EventPropagation>,
+    ) {
+        self.pending_focus = Some((new_focus, propagations));
+    }
+
+    pub(crate) fn take_pending_focus(&mut self) -> Option<(Option<NodeId>, Vec<EventPropagation>)> {
+        self.pending_focus.take()
+    }
+
+    /// Specify the transformations that are being animated, so the pointer events hit the elements where they are displayed.
+    pub fn set_animated_transforms(&mut self, animated_transforms: AnimatedTransforms) {
+        self.animated_transforms = animated_transforms;
+    }
+
+    pub(crate) fn animated_transforms(&self) -> &AnimatedTransforms {
+        &self.animated_transforms
+    }
+
+    /// Get the element that is capturing the pointer.
+    pub fn pointer_capture(&self) -> Option<NodeId> {
+        *self.pointer_capture.lock().unwrap()
+    }
+
+    pub(crate) fn release_pointer_capture(&mut self) {
+        *self.pointer_capture.lock().unwrap() = None;
+    }
+
+    /// Let the listeners of a pointer event capture the pointer for its element.
+    pub(crate) fn with_pointer_capture(&self, event: DomEvent) -> DomEvent {
+        match event.data {
+            DomEventData::Pointer(data) => {
+                let node_id = event.node_id;
+                let pointer_capture = self.pointer_capture.clone();
+                let capture = PointerCapture::new(move |captured| {
+                    let mut pointer_capture = pointer_capture.lock().unwrap();
+                    if captured {
+                        *pointer_capture = Some(node_id);
+                    } else if *pointer_capture == Some(node_id) {
+                        *pointer_capture = None;
+                    }
+                });
+                DomEvent {
+                    data: DomEventData::Pointer(data.with_pointer_capture(capture)),
+                    ..event
+                }
+            }
+            _ => event,
+        }
+    }
+
+    /// Turn the releases of the mouse buttons and the touches into clicks.
+    /// Only the elements where both the press and the release happened are clicked,
+    /// and two left clicks on the same elements within the double click interval also cause a `doubleclick`.
+    pub(crate) fn measure_click_events(
+        &mut self,
+        events: &EventsQueue,
+        potential_events: &NodesEvents,
+    ) -> NodesEvents {
+        let mut click_events = NodesEvents::default();
+
+        let hit_nodes = |event_name: &str| {
+            potential_events
+                .get(event_name)
+                .into_iter()
+                .flatten()
+                .map(|(node_id, _)| *node_id)
+                .collect::<Vec<NodeId>>()
+        };
+
+        for event in events {
+            // Touches behave like the left button
+            let (name, cursor, button) = match event {
+                FreyaEvent::Mouse {
+                    name,
+                    cursor,
+                    button: Some(button),
+                } => (name.as_str(), *cursor, *button),
+                FreyaEvent::Touch { name, location, .. } => {
+                    (name.as_str(), *location, MouseButton::Left)
+                }
+                _ => continue,
+            };
+
+            match name {
+                "mousedown" | "touchstart" => {
+                    self.pressed_nodes.insert(button, hit_nodes(name));
+                }
+                "mouseup" | "touchend" => {
+                    let pressed_nodes = self.pressed_nodes.remove(&button).unwrap_or_default();
+                    let clicked_nodes = hit_nodes(name)
+                        .into_iter()
+                        .filter(|node_id| pressed_nodes.contains(node_id))
+                        .collect::<Vec<NodeId>>();
+
+                    if clicked_nodes.is_empty() {
+                        continue;
+                    }
+
+                    let click_name = match button {
+                        MouseButton::Left => "click",
+                        MouseButton::Right => "contextmenu",
+                        MouseButton::Middle => "middleclick",
+                        MouseButton::Other(_) => continue,
+                    };
+
+                    let mut emit = |event_name: &str, nodes: &[NodeId]| {
+                        if nodes.is_empty() {
+                            return;
+                        }
+                        let request = FreyaEvent::Mouse {
+                            name: event_name.to_string(),
+                            cursor,
+                            button: Some(button),
+                        };
+                        click_events
+                            .entry(event_name.to_string())
+                            .or_default()
+                            .extend(nodes.iter().map(|node_id| (*node_id, request.clone())));
+                    };
+
+                    emit(click_name, &clicked_nodes);
+
+                    if button == MouseButton::Left {
+                        let now = Instant::now();
+                        match self.last_click.take() {
+                            Some((last_click, last_clicked_nodes))
+                                if now.duration_since(last_click) <= self.double_click_interval =>
+                            {
+                                let double_clicked_nodes = clicked_nodes
+                                    .into_iter()
+                                    .filter(|node_id| last_clicked_nodes.contains(node_id))
+                                    .collect::<Vec<NodeId>>();
+                                emit("doubleclick", &double_clicked_nodes);
+                            }
+                            _ => {
+                                self.last_click = Some((now, clicked_nodes));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        click_events
+    }
+
+    /// Emit the `filehovercancel` to the elements that were under the dragged files,
+    /// as the cursor they are canceled with might not be up to date.
+    pub(crate) fn measure_file_hover_cancel_events(
+        &mut self,
+        events: &EventsQueue,
+        potential_events: &NodesEvents,
+    ) -> NodesEvents {
+        let mut cancel_events = NodesEvents::default();
+
+        for event in events {
+            match event.get_name() {
+                "filehover" => {
+                    self.file_hovered_nodes = potential_events
+                        .get("filehover")
+                        .into_iter()
+                        .flatten()
+                        .map(|(node_id, _)| *node_id)
+                        .collect();
+                }
+                "filedrop" => {
+                    self.file_hovered_nodes.clear();
+                }
+                "filehovercancel" => {
+                    let file_hovered_nodes = std::mem::take(&mut self.file_hovered_nodes);
+                    cancel_events
+                        .entry("filehovercancel".to_string())
+                        .or_default()
+                        .extend(
+                            file_hovered_nodes
+                                .into_iter()
+                                .map(|node_id| (node_id, event.clone())),
+                        );
+                }
+                _ => {}
+            }
+        }
+
+        cancel_events
+    }
+
     /// Update the Element states given the new events
     pub fn process_events(
         &mut self,
         events_to_emit: Vec<DomEvent>,
         events: &[FreyaEvent],
+        hovered_nodes: &[NodeId],
         event_emitter: &EventEmitter,
     ) -> FxHashMap<String, Vec<(NodeId, FreyaEvent)>> {
         let mut new_events = FxHashMap::<String, Vec<(NodeId, FreyaEvent)>>::default();
 
-        for (element, element_state) in self.states.iter_mut() {
-            {
-                let no_recent_mouse_movement_on_me = events_to_emit
-                    .iter()
-                    .find_map(|event| {
-                        if event.does_move_cursor() && &event.node_id == element {
-                            Some(false)
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or(true);
-
-                let recent_mouse_movement_event = events
-                    .iter()
-                    .find(|event| {
-                        if let FreyaEvent::Mouse { name, .. } = event {
-                            does_event_move_cursor(name)
-                        } else {
-                            false
-                        }
-                    })
-                    .cloned();
-
-                if element_state.hovered && no_recent_mouse_movement_on_me {
-                    if let Some(FreyaEvent::Mouse { cursor, button, .. }) =
-                        recent_mouse_movement_event
-                    {
-                        let events = new_events.entry("mouseleave".to_string()).or_default();
-                        events.push((
-                            *element,
-                            FreyaEvent::Mouse {
-                                name: "mouseleave".to_string(),
-                                cursor,
-                                button,
-                            },
-                        ));
-
-                        // Mark the element as no longer being hovered
-                        element_state.hovered = false;
-                    }
+        let recent_mouse_movement_event = events
+            .iter()
+            .find(|event| {
+                if let FreyaEvent::Mouse { name, .. } = event {
+                    // The cursor leaving the window also moves it
+                    does_event_move_cursor(name) || name == "mouseleave"
+                } else {
+                    false
+                }
+            })
+            .cloned();
+
+        // Elements that are no longer under the cursor are left
+        if let Some(FreyaEvent::Mouse { cursor, button, .. }) = &recent_mouse_movement_event {
+            for (element, element_state) in self.states.iter_mut() {
+                if element_state.hovered && !hovered_nodes.contains(element) {
+                    let events = new_events.entry("mouseleave".to_string()).or_default();
+                    events.push((
+                        *element,
+                        FreyaEvent::Mouse {
+                            name: "mouseleave".to_string(),
+                            cursor: *cursor,
+                            button: *button,
+                        },
+                    ));
+
+                    // Mark the element as no longer being hovered
+                    element_state.hovered = false;
                 }
             }
         }
 
-        // All these events will mark the node as being hovered
-        // "mouseover" "mouseenter" "pointerover"  "pointerenter"
-
         // Emit valid events
         for event in &events_to_emit {
-            let id = &event.node_id;
-
             let should_trigger = match event.name.as_str() {
-                name @ "mouseover"
-                | name @ "mouseenter"
-                | name @ "pointerover"
-                | name @ "pointerenter" => {
-                    if !self.states.contains_key(id) {
-                        self.states.insert(*id, ElementState::default());
-                    }
-
-                    let node_state = self.states.get_mut(id).unwrap();
-
-                    if name == "mouseenter" || name == "pointerenter" {
-                        // If the event is already being hovered then it's pointless to trigger the movement event
-                        !node_state.hovered
-                    } else {
-                        true
-                    }
+                "mouseenter" | "pointerenter" => {
+                    // If the element is already being hovered then it's pointless to trigger the event
+                    !self
+                        .states
+                        .get(&event.node_id)
+                        .map(|state| state.hovered)
+                        .unwrap_or_default()
                 }
                 _ => true,
             };
@@ -109,21 +369,15 @@ impl EventsProcessor {
             }
         }
 
-        // Update the internal states of elements given the events
-        // e.g `mouseover` will mark the element as hovered.
-        for event in events_to_emit {
-            let id = &event.node_id;
-            if does_event_move_cursor(event.name.as_str()) {
-                if !self.states.contains_key(id) {
-                    self.states.insert(*id, ElementState::default());
-                }
-
-                let node_state = self.states.get_mut(id).unwrap();
-
-                node_state.hovered = true;
+        // Mark the elements under the cursor as hovered
+        if recent_mouse_movement_event.is_some() {
+            for node_id in hovered_nodes {
+                self.states.entry(*node_id).or_default().hovered = true;
             }
         }
 
+        self.states.retain(|_, state| state.hovered);
+
         new_events
     }
 }
@@ -0,0 +1,209 @@
+use dioxus_native_core::prelude::NodeImmutableDioxusExt;
+use dioxus_native_core::real_dom::NodeImmutable;
+use dioxus_native_core::NodeId;
+use freya_dom::prelude::FreyaDOM;
+use freya_elements::events::keyboard::{Key, Modifiers};
+use freya_elements::events::FocusData;
+use freya_node_state::Focus;
+use uuid::Uuid;
+
+use crate::dom_events::{DomEvent, DomEventData};
+use crate::events_processor::EventsProcessor;
+use crate::freya_events::FreyaEvent;
+use crate::{EventEmitter, EventsQueue, NodesEvents};
+
+/// Get the elements reachable with `Tab` in the order they are focused,
+/// first the ones with a positive `tab_index` in ascending order and then the rest in DOM order.
+pub fn tab_order(fdom: &FreyaDOM) -> Vec<NodeId> {
+    let mut nodes = Vec::new();
+
+    fdom.rdom().traverse_depth_first(|node| {
+        let focus = node.get::<Focus>().unwrap();
+        if focus.is_tabbable() {
+            nodes.push((node.id(), focus.tab_index));
+        }
+    });
+
+    // The sort is stable, so elements with the same index keep their DOM order
+    nodes.sort_by_key(|(_, tab_index)| (*tab_index == 0, *tab_index));
+
+    nodes.into_iter().map(|(node_id, _)| node_id).collect()
+}
+
+/// Get the element that is focused after pressing `Tab`, or `Shift + Tab` when going `backwards`.
+pub fn next_tab_focus(
+    fdom: &FreyaDOM,
+    focused_node: Option<NodeId>,
+    backwards: bool,
+) -> Option<NodeId> {
+    let order = tab_order(fdom);
+    if order.is_empty() {
+        return None;
+    }
+
+    let position = focused_node
+        .and_then(|focused_node| order.iter().position(|node_id| *node_id == focused_node));
+
+    let next = match (position, backwards) {
+        (Some(position), false) => (position + 1) % order.len(),
+        (Some(position), true) => (position + order.len() - 1) % order.len(),
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+    };
+
+    Some(order[next])
+}
+
+/// Get the closest focusable element, starting from the given element and going up to the root.
+fn focusable_ancestor(node_id: NodeId, fdom: &FreyaDOM) -> Option<NodeId> {
+    let rdom = fdom.rdom();
+
+    let mut node_id = Some(node_id);
+    while let Some(id) = node_id {
+        let node = rdom.get(id)?;
+        if node.get::<Focus>().unwrap().focusable {
+            return Some(id);
+        }
+        node_id = node.parent_id();
+    }
+
+    None
+}
+
+/// Measure where the focus moves to given the new events,
+/// returns `None` if it stays in the same element.
+pub fn measure_focus(
+    events: &EventsQueue,
+    potential_events: &NodesEvents,
+    fdom: &FreyaDOM,
+    focused_node: Option<NodeId>,
+) -> Option<Option<NodeId>> {
+    let mut new_focus = None;
+
+    for event in events {
+        match event {
+            FreyaEvent::Mouse { name, .. } | FreyaEvent::Touch { name, .. }
+                if name == "mousedown" || name == "touchstart" =>
+            {
+                // Pressing an element focuses it, and pressing anywhere else removes the focus.
+                // Elements are sorted from the bottom to the top.
+                let pressed_node = potential_events
+                    .get(name)
+                    .and_then(|nodes| nodes.last())
+                    .map(|(node_id, _)| *node_id);
+                new_focus =
+                    Some(pressed_node.and_then(|node_id| focusable_ancestor(node_id, fdom)));
+            }
+            FreyaEvent::Keyboard {
+                name,
+                key: Key::Tab,
+                modifiers,
+                ..
+            } if name == "keydown" => {
+                let current_focus = new_focus.unwrap_or(focused_node);
+                new_focus = Some(next_tab_focus(
+                    fdom,
+                    current_focus,
+                    modifiers.contains(Modifiers::SHIFT),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    new_focus.filter(|new_focus| *new_focus != focused_node)
+}
+
+/// Move the focus to another element, or remove it with `None`.
+/// The `blur` and `focus` events are emitted and the `use_node_focus` hooks of both elements are notified.
+///
+/// Returns `true` if the focus changed.
+pub fn set_focus(
+    new_focus: Option<NodeId>,
+    fdom: &FreyaDOM,
+    events_processor: &mut EventsProcessor,
+    event_emitter: &EventEmitter,
+) -> bool {
+    let old_focus = events_processor.focused_node();
+    if old_focus == new_focus {
+        return false;
+    }
+
+    events_processor.set_focused_node(new_focus);
+
+    let blur = old_focus.map(|node_id| (node_id, "blur", false));
+    let focus = new_focus.map(|node_id| (node_id, "focus", true));
+
+    for (node_id, event_name, is_focused) in blur.into_iter().chain(focus) {
+        let node = if let Some(node) = fdom.rdom().get(node_id) {
+            node
+        } else {
+            continue;
+        };
+
+        if let Some(focus_reference) = &node.get::<Focus>().unwrap().focus_reference {
+            focus_reference.sender.send(is_focused).ok();
+        }
+
+        if let Some(element_id) = node.mounted_id() {
+            event_emitter
+                .send(DomEvent {
+                    name: event_name.to_string(),
+                    node_id,
+                    element_id,
+                    data: DomEventData::Focus(FocusData::new()),
+                })
+                .unwrap();
+        }
+    }
+
+    true
+}
+
+/// Move the focus caused by the last processed events, once their listeners ran,
+/// unless one of them prevented their default action.
+///
+/// Returns `true` if the focus changed.
+pub fn apply_pending_focus(
+    fdom: &FreyaDOM,
+    events_processor: &mut EventsProcessor,
+    event_emitter: &EventEmitter,
+) -> bool {
+    match events_processor.take_pending_focus() {
+        Some((new_focus, propagations))
+            if !propagations
+                .iter()
+                .any(|propagation| propagation.is_default_prevented()) =>
+        {
+            set_focus(new_focus, fdom, events_processor, event_emitter)
+        }
+        _ => false,
+    }
+}
+
+/// Focus the element bound to the `use_node_focus` hook with the given ID.
+///
+/// Returns `true` if the focus changed.
+pub fn focus_reference(
+    focus_id: Uuid,
+    fdom: &FreyaDOM,
+    events_processor: &mut EventsProcessor,
+    event_emitter: &EventEmitter,
+) -> bool {
+    let mut found_node = None;
+
+    fdom.rdom().traverse_depth_first(|node| {
+        let focus = node.get::<Focus>().unwrap();
+        if let Some(focus_reference) = &focus.focus_reference {
+            if focus_reference.id == focus_id {
+                found_node = Some(node.id());
+            }
+        }
+    });
+
+    if let Some(node_id) = found_node {
+        set_focus(Some(node_id), fdom, events_processor, event_emitter)
+    } else {
+        false
+    }
+}
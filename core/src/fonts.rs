@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+use skia_safe::textlayout::{FontCollection, TypefaceFontProvider};
+use skia_safe::FontMgr;
+use tracing::warn;
+
+/// Where the data of a custom font comes from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FontSource {
+    /// Font data embedded in the binary, e.g. with `include_bytes!`.
+    Bytes(&'static [u8]),
+    /// Path to a font file, it will be read when the app is launched.
+    File(PathBuf),
+}
+
+/// A custom font registered under the given family name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomFont {
+    pub family: String,
+    pub source: FontSource,
+}
+
+impl CustomFont {
+    pub fn from_bytes(family: &str, bytes: &'static [u8]) -> Self {
+        Self {
+            family: family.to_string(),
+            source: FontSource::Bytes(bytes),
+        }
+    }
+
+    pub fn from_file(family: &str, path: impl Into<PathBuf>) -> Self {
+        Self {
+            family: family.to_string(),
+            source: FontSource::File(path.into()),
+        }
+    }
+}
+
+/// Create a [`FontCollection`] with the system fonts, the given custom fonts and a default font family.
+///
+/// The default family is used for any text that doesn't specify a `font_family`,
+/// or whose families couldn't be found.
+pub fn create_font_collection(fonts: &[CustomFont], default_font: &str) -> FontCollection {
+    let font_mgr = FontMgr::default();
+    let mut provider = TypefaceFontProvider::new();
+
+    for font in fonts {
+        let data = match &font.source {
+            FontSource::Bytes(bytes) => Cow::Borrowed(*bytes),
+            FontSource::File(path) => match std::fs::read(path) {
+                Ok(bytes) => Cow::Owned(bytes),
+                Err(err) => {
+                    warn!("Could not read the font file {path:?}: {err}");
+                    continue;
+                }
+            },
+        };
+
+        if let Some(typeface) = font_mgr.new_from_data(&data, None) {
+            provider.register_typeface(typeface, Some(&font.family));
+        } else {
+            warn!("Could not load the font <{}>", font.family);
+        }
+    }
+
+    let mut font_collection = FontCollection::new();
+    font_collection.set_asset_font_manager(FontMgr::from(provider));
+    font_collection.set_default_font_manager(font_mgr, default_font);
+    font_collection
+}
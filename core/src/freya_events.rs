@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use freya_elements::events::keyboard::{Code, Key, Modifiers};
 use torin::prelude::*;
 use winit::event::{Force, MouseButton, TouchPhase};
@@ -32,6 +34,20 @@ pub enum FreyaEvent {
         phase: TouchPhase,
         force: Option<Force>,
     },
+    /// A Composition event from an input method.
+    Composition {
+        name: String,
+        data: String,
+        cursor: Option<(usize, usize)>,
+    },
+    /// Text committed by an input method.
+    TextInput { name: String, text: String },
+    /// A File being dragged from the OS over the window.
+    File {
+        name: String,
+        cursor: CursorPoint,
+        file_path: Option<PathBuf>,
+    },
 }
 
 impl FreyaEvent {
@@ -41,6 +57,9 @@ impl FreyaEvent {
             Self::Wheel { name, .. } => name,
             Self::Keyboard { name, .. } => name,
             Self::Touch { name, .. } => name,
+            Self::Composition { name, .. } => name,
+            Self::TextInput { name, .. } => name,
+            Self::File { name, .. } => name,
         }
     }
 
@@ -50,9 +69,20 @@ impl FreyaEvent {
             Self::Wheel { name, .. } => *name = new_name,
             Self::Keyboard { name, .. } => *name = new_name,
             Self::Touch { name, .. } => *name = new_name,
+            Self::Composition { name, .. } => *name = new_name,
+            Self::TextInput { name, .. } => *name = new_name,
+            Self::File { name, .. } => *name = new_name,
         }
     }
 
+    /// Check if this event targets the focused element instead of the elements under the cursor.
+    pub fn targets_focus(&self) -> bool {
+        matches!(
+            self,
+            Self::Keyboard { .. } | Self::Composition { .. } | Self::TextInput { .. }
+        )
+    }
+
     pub fn is_pointer_event(&self) -> bool {
         self.get_name().starts_with("point")
     }
@@ -0,0 +1,247 @@
+use dioxus_native_core::real_dom::NodeImmutable;
+use dioxus_native_core::NodeId;
+use freya_dom::prelude::FreyaDOM;
+use freya_node_state::{Shape, Style, Transform};
+use rustc_hash::FxHashMap;
+use skia_safe::{Matrix, Point, Rect};
+use torin::prelude::{Area, CursorPoint};
+
+use crate::ViewportsCollection;
+
+/// Transformations of the Nodes whose values are being animated, e.g by transitions.
+/// They replace the ones from their layout and style.
+#[derive(Default, Clone, Debug)]
+pub struct AnimatedTransforms {
+    /// Current rotation in degrees.
+    pub rotations: FxHashMap<NodeId, f32>,
+    /// Area where a Node whose layout is being animated is currently displayed.
+    pub areas: FxHashMap<NodeId, Area>,
+    /// Current corner radius.
+    pub radii: FxHashMap<NodeId, f32>,
+}
+
+impl AnimatedTransforms {
+    /// Get the corner radius a Node is currently displayed with.
+    pub fn radius(&self, node_id: NodeId, style: &Style) -> f32 {
+        self.radii.get(&node_id).copied().unwrap_or(style.radius)
+    }
+}
+
+/// Get the transformation that moves and scales a Node from its layout area to where it's being animated.
+pub fn animated_layout_matrix(area: &Area, animated_area: &Area) -> Matrix {
+    let scale = |from: f32, to: f32| if from > 0.0 { to / from } else { 1.0 };
+    let mut matrix = Matrix::translate((animated_area.min_x(), animated_area.min_y()));
+    matrix.pre_scale(
+        (
+            scale(area.width(), animated_area.width()),
+            scale(area.height(), animated_area.height()),
+        ),
+        None,
+    );
+    matrix.pre_translate((-area.min_x(), -area.min_y()));
+    matrix
+}
+
+/// Get the animated layout and the rotation around its center of a Node, if any.
+fn own_matrix(
+    node_id: NodeId,
+    fdom: &FreyaDOM,
+    animated_transforms: &AnimatedTransforms,
+) -> Option<Matrix> {
+    let area = fdom.layout().get(node_id)?.area;
+
+    let layout_matrix = animated_transforms
+        .areas
+        .get(&node_id)
+        .map(|animated_area| animated_layout_matrix(&area, animated_area));
+
+    let rotate_degs = animated_transforms
+        .rotations
+        .get(&node_id)
+        .copied()
+        .or_else(|| fdom.rdom().get(node_id)?.get::<Transform>()?.rotate_degs);
+    let rotation_matrix = rotate_degs.map(|rotate_degs| {
+        let mut matrix = Matrix::new_identity();
+        matrix.set_rotate(
+            rotate_degs,
+            Some(Point {
+                x: area.min_x() + area.width() / 2.0,
+                y: area.min_y() + area.height() / 2.0,
+            }),
+        );
+        matrix
+    });
+
+    concat(layout_matrix, rotation_matrix)
+}
+
+/// Concatenate two optional transformations, `None` being the identity.
+fn concat(a: Option<Matrix>, b: Option<Matrix>) -> Option<Matrix> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(Matrix::concat(&a, &b)),
+        (a, b) => a.or(b),
+    }
+}
+
+/// Transformations applied to the Nodes when they are painted, computed once per Node.
+///
+/// A Node is painted with its own transformation followed by the ones of its ancestors, from the root,
+/// so the transformations of the ancestors are shared with their descendants.
+pub struct NodesMatrices<'a> {
+    fdom: &'a FreyaDOM,
+    animated_transforms: &'a AnimatedTransforms,
+    /// Transformations of each Node and its ancestors, `None` if there are none.
+    chains: FxHashMap<NodeId, Option<Matrix>>,
+}
+
+impl<'a> NodesMatrices<'a> {
+    pub fn new(fdom: &'a FreyaDOM, animated_transforms: &'a AnimatedTransforms) -> Self {
+        Self {
+            fdom,
+            animated_transforms,
+            chains: FxHashMap::default(),
+        }
+    }
+
+    /// Get the transformations of the Nodes whose values are being animated.
+    pub fn animated_transforms(&self) -> &'a AnimatedTransforms {
+        self.animated_transforms
+    }
+
+    /// Get the transformation applied to a Node when it's painted, `None` if it isn't transformed.
+    pub fn get(&mut self, node_id: NodeId) -> Option<Matrix> {
+        let parent_chain = self
+            .fdom
+            .rdom()
+            .get(node_id)
+            .and_then(|node| node.parent_id())
+            .and_then(|parent_id| self.chain(parent_id));
+        concat(
+            own_matrix(node_id, self.fdom, self.animated_transforms),
+            parent_chain,
+        )
+    }
+
+    /// Get the transformations of a Node and its ancestors, from the root.
+    fn chain(&mut self, node_id: NodeId) -> Option<Matrix> {
+        if let Some(chain) = self.chains.get(&node_id) {
+            return *chain;
+        }
+
+        let parent_chain = self
+            .fdom
+            .rdom()
+            .get(node_id)
+            .and_then(|node| node.parent_id())
+            .and_then(|parent_id| self.chain(parent_id));
+        let chain = concat(
+            parent_chain,
+            own_matrix(node_id, self.fdom, self.animated_transforms),
+        );
+        self.chains.insert(node_id, chain);
+        chain
+    }
+}
+
+/// Check if a point is inside a rect, including its edges.
+fn rect_contains(rect: &Rect, point: Point) -> bool {
+    point.x >= rect.left && point.x <= rect.right && point.y >= rect.top && point.y <= rect.bottom
+}
+
+/// Check if a point is inside an area with rounded corners.
+pub fn rounded_area_contains(area: &Area, radius: f32, (x, y): (f32, f32)) -> bool {
+    if x < area.min_x() || x > area.max_x() || y < area.min_y() || y > area.max_y() {
+        return false;
+    }
+
+    let radius = radius.min(area.width() / 2.0).min(area.height() / 2.0);
+    if radius <= 0.0 {
+        return true;
+    }
+
+    // Distance from the point to the inner area whose corners are not rounded
+    let dx = (area.min_x() + radius - x)
+        .max(x - (area.max_x() - radius))
+        .max(0.0);
+    let dy = (area.min_y() + radius - y)
+        .max(y - (area.max_y() - radius))
+        .max(0.0);
+
+    dx * dx + dy * dy <= radius * radius
+}
+
+/// Check if a point of the window hits the painted parts of a Node.
+///
+/// The point is mapped to the space where the Node is painted, undoing its transformations and the ones of its ancestors,
+/// and then checked against its rounded corners, its shape and the viewports that clip it.
+pub fn hit_test(
+    node_id: NodeId,
+    point: CursorPoint,
+    fdom: &FreyaDOM,
+    viewports_collection: &ViewportsCollection,
+    nodes_matrices: &mut NodesMatrices,
+) -> bool {
+    let rdom = fdom.rdom();
+    let (node, area) = if let Some(node_and_area) = rdom
+        .get(node_id)
+        .zip(fdom.layout().get(node_id).map(|areas| areas.area))
+    {
+        node_and_area
+    } else {
+        return false;
+    };
+
+    let point = Point::new(point.x as f32, point.y as f32);
+    let rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+
+    let point = if let Some(matrix) = nodes_matrices.get(node_id) {
+        // Early reject with the bounds of the transformed area before undoing the transformation
+        if !rect_contains(&matrix.map_rect(rect).0, point) {
+            return false;
+        }
+        if let Some(inverse) = matrix.invert() {
+            inverse.map_point(point)
+        } else {
+            return false;
+        }
+    } else if rect_contains(&rect, point) {
+        point
+    } else {
+        return false;
+    };
+    let point = (point.x, point.y);
+
+    let style = node.get::<Style>().unwrap();
+    let animated_transforms = nodes_matrices.animated_transforms();
+    if !rounded_area_contains(&area, animated_transforms.radius(node_id, &style), point) {
+        return false;
+    }
+
+    // Shapes are only hit on their painted parts
+    let shape = node.get::<Shape>().unwrap();
+    if !shape.hit_test(&area, point, &style) {
+        return false;
+    }
+
+    // The viewports clip the Node in the same space where it's painted
+    if let Some((_, viewports)) = viewports_collection.get(&node_id) {
+        for viewport_id in viewports {
+            let viewport = viewports_collection.get(viewport_id).unwrap().0;
+            if let Some(viewport) = viewport {
+                let radius = rdom
+                    .get(*viewport_id)
+                    .and_then(|viewport_node| {
+                        viewport_node
+                            .get::<Style>()
+                            .map(|style| animated_transforms.radius(*viewport_id, &style))
+                    })
+                    .unwrap_or_default();
+                if !rounded_area_contains(&viewport, radius, point) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    true
+}
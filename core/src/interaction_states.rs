@@ -0,0 +1,145 @@
+use dioxus_native_core::real_dom::NodeImmutable;
+use dioxus_native_core::NodeId;
+use freya_dom::prelude::FreyaDOM;
+use freya_node_state::{Border, Style};
+use rustc_hash::FxHashMap;
+use skia_safe::Color;
+
+use crate::{EventsQueue, NodesEvents};
+
+/// Interaction state of an element, used to resolve its state-scoped styles
+/// like `hover_background`, `pressed_background` or `focus_border`.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct InteractionState {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+}
+
+impl InteractionState {
+    /// Get the background of an element in this state.
+    pub fn background(&self, style: &Style) -> Color {
+        let state_background = if self.pressed && style.pressed_background.is_some() {
+            style.pressed_background
+        } else if self.hovered {
+            style.hover_background
+        } else {
+            None
+        };
+        state_background.unwrap_or(style.background)
+    }
+
+    /// Get the border of an element in this state.
+    pub fn border(&self, style: &Style) -> Option<Border> {
+        if self.focused {
+            style.focus_border
+        } else {
+            None
+        }
+    }
+}
+
+/// [`InteractionState`] of the elements that have state-scoped styles.
+#[derive(Default)]
+pub struct InteractionStates(FxHashMap<NodeId, InteractionState>);
+
+impl InteractionStates {
+    /// Get the [`InteractionState`] of an element.
+    pub fn get(&self, node_id: &NodeId) -> InteractionState {
+        self.0.get(node_id).copied().unwrap_or_default()
+    }
+
+    /// Keep only the elements that pass the given predicate.
+    pub(crate) fn retain(&mut self, predicate: impl Fn(&NodeId) -> bool) {
+        self.0.retain(|node_id, _| predicate(node_id));
+    }
+
+    /// Mark only the given elements with some flag, returns `true` if any element changed.
+    pub(crate) fn set_only(
+        &mut self,
+        nodes: &[NodeId],
+        flag: fn(&mut InteractionState) -> &mut bool,
+    ) -> bool {
+        let mut changed = false;
+
+        for (node_id, state) in self.0.iter_mut() {
+            let value = flag(state);
+            if *value && !nodes.contains(node_id) {
+                *value = false;
+                changed = true;
+            }
+        }
+
+        for node_id in nodes {
+            let value = flag(self.0.entry(*node_id).or_default());
+            if !*value {
+                *value = true;
+                changed = true;
+            }
+        }
+
+        self.0
+            .retain(|_, state| *state != InteractionState::default());
+
+        changed
+    }
+}
+
+/// Update the [`InteractionStates`] given the new events,
+/// returns `true` if the elements must be repainted.
+pub fn measure_interaction_states(
+    events: &EventsQueue,
+    potential_events: &NodesEvents,
+    fdom: &FreyaDOM,
+    interaction_states: &mut InteractionStates,
+) -> bool {
+    let rdom = fdom.rdom();
+
+    // Elements with state-scoped styles under the cursor
+    let styled_nodes = |event_name: &str| -> Vec<NodeId> {
+        potential_events
+            .get(event_name)
+            .into_iter()
+            .flatten()
+            .filter_map(|(node_id, _)| {
+                let node = rdom.get(*node_id)?;
+                let style = node.get::<Style>()?;
+                if style.has_interaction_styles() {
+                    Some(*node_id)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    };
+
+    let mut changed = false;
+
+    for event in events {
+        let event_name = event.get_name();
+        match event_name {
+            "mouseleave" => {
+                // The cursor left the window
+                changed |= interaction_states.set_only(&[], |state| &mut state.hovered);
+            }
+            "mouseover" | "touchmove" => {
+                let hovered = styled_nodes(event_name);
+                changed |= interaction_states.set_only(&hovered, |state| &mut state.hovered);
+            }
+            "mousedown" | "touchstart" => {
+                let pressed = styled_nodes(event_name);
+                changed |= interaction_states.set_only(&pressed, |state| &mut state.pressed);
+            }
+            "click" | "mouseup" => {
+                changed |= interaction_states.set_only(&[], |state| &mut state.pressed);
+            }
+            "touchend" | "touchcancel" => {
+                changed |= interaction_states.set_only(&[], |state| &mut state.pressed);
+                changed |= interaction_states.set_only(&[], |state| &mut state.hovered);
+            }
+            _ => {}
+        }
+    }
+
+    changed
+}
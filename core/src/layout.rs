@@ -13,7 +13,7 @@ pub fn process_layout(
     scale_factor: f32,
 ) -> (Layers, ViewportsCollection) {
     let rdom = fdom.rdom();
-    let dom_adapter = DioxusDOMAdapter::new(rdom);
+    let dom_adapter = DioxusDOMAdapter::new(rdom).with_size_overrides(fdom.size_overrides());
     let skia_measurer = SkiaMeasurer::new(rdom, font_collection);
 
     // Finds the best Node from where to start measuring
@@ -9,7 +9,11 @@ use torin::prelude::Area;
 pub mod dom_events;
 pub mod events;
 pub mod events_processor;
+pub mod focus;
+pub mod fonts;
 pub mod freya_events;
+pub mod hit_test;
+pub mod interaction_states;
 pub mod layers;
 pub mod layout;
 pub mod node;
@@ -26,7 +30,11 @@ pub mod prelude {
     pub use crate::dom_events::*;
     pub use crate::events::*;
     pub use crate::events_processor::*;
+    pub use crate::focus::*;
+    pub use crate::fonts::*;
     pub use crate::freya_events::*;
+    pub use crate::hit_test::*;
+    pub use crate::interaction_states::*;
     pub use crate::layers::*;
     pub use crate::layout::*;
     pub use crate::node::*;
@@ -98,6 +98,10 @@ impl<'a> Iterator for NodeStateIterator<'a> {
             )),
             16 => Some(("scroll_x", AttributeType::Measure(self.state.size.scroll_x))),
             17 => Some(("scroll_y", AttributeType::Measure(self.state.size.scroll_y))),
+            18 => Some((
+                "text_overflow",
+                AttributeType::Text(self.state.font_style.text_overflow.pretty()),
+            )),
             _ => None,
         }
     }
@@ -1,12 +1,26 @@
+use dioxus_native_core::real_dom::NodeImmutable;
 use dioxus_native_core::NodeId;
-use freya_dom::prelude::FreyaDOM;
+use freya_dom::prelude::{DioxusDOM, FreyaDOM};
 use freya_layout::Layers;
+use freya_node_state::Style;
+use rustc_hash::FxHashMap;
 use skia_safe::textlayout::FontCollection;
 use torin::prelude::Area;
 
 use crate::ViewportsCollection;
 
-/// Render the layout
+/// Boundary of a Node that is rendered together with all its descendants,
+/// so their content can be blurred or blended as a whole.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderGroup {
+    Start,
+    End,
+}
+
+/// Render the layout.
+///
+/// Nodes with effects (`blur` or `blend_mode`) and all their descendants are rendered in a row,
+/// `group_hook` is called before and after them.
 pub fn process_render<HookOptions>(
     viewports_collection: &ViewportsCollection,
     dom: &FreyaDOM,
@@ -21,43 +35,191 @@ pub fn process_render<HookOptions>(
         &ViewportsCollection,
         &mut HookOptions,
     ),
+    group_hook: impl Fn(&FreyaDOM, &NodeId, RenderGroup, &ViewportsCollection, &mut HookOptions),
 ) {
     let mut layers_nums: Vec<&i16> = layers.layers.keys().collect();
 
     // Order the layers from top to bottom
     layers_nums.sort();
 
+    // Find the closest group of every grouped node
+    let mut groups = FxHashMap::default();
+    let has_groups = layers
+        .layers
+        .values()
+        .flatten()
+        .any(|node_id| is_group(dom.rdom(), *node_id));
+    if has_groups {
+        collect_groups(dom.rdom(), dom.rdom().root_id(), None, &mut groups);
+    }
+
+    // Collect the members of every group from the bottom to the top layer
+    let mut members: FxHashMap<NodeId, Vec<NodeId>> = FxHashMap::default();
+    if !groups.is_empty() {
+        for layer_num in &layers_nums {
+            for node_id in layers.layers.get(layer_num).unwrap() {
+                if let Some(group) = groups.get(node_id) {
+                    members.entry(*group).or_default().push(*node_id);
+                }
+            }
+        }
+    }
+
+    let renderer = LayersRenderer {
+        viewports_collection,
+        dom,
+        groups,
+        members,
+        render_hook,
+        group_hook,
+    };
+
     // Render all the layers from the bottom to the top
     for layer_num in &layers_nums {
         let layer = layers.layers.get(layer_num).unwrap();
-        'elements: for node_id in layer {
-            let viewports = viewports_collection.get(node_id);
-            let layout = dom.layout();
-            let areas = layout.get(*node_id);
-
-            if let Some(areas) = areas {
-                // Skip elements that are completely out of any their parent's viewport
-                if let Some((_, viewports)) = viewports {
-                    for viewport_id in viewports {
-                        let viewport = viewports_collection.get(viewport_id).unwrap().0;
-                        if let Some(viewport) = viewport {
-                            if !viewport.intersects(&areas.area) {
-                                continue 'elements;
-                            }
+        for node_id in layer {
+            // Grouped nodes are rendered by their group
+            if renderer.groups.contains_key(node_id) {
+                continue;
+            }
+            renderer.render_node(node_id, font_collection, hook_options);
+        }
+    }
+}
+
+/// Whether the given node needs its descendants to be rendered along with it.
+fn is_group(rdom: &DioxusDOM, node_id: NodeId) -> bool {
+    rdom.get(node_id)
+        .and_then(|node| {
+            node.get::<Style>()
+                .map(|style| style.blur > 0.0 || style.blend_mode.is_some())
+        })
+        .unwrap_or_default()
+}
+
+/// Map every descendant of a group to its closest group.
+fn collect_groups(
+    rdom: &DioxusDOM,
+    node_id: NodeId,
+    group: Option<NodeId>,
+    groups: &mut FxHashMap<NodeId, NodeId>,
+) {
+    let child_ids = rdom
+        .get(node_id)
+        .map(|node| node.child_ids())
+        .unwrap_or_default();
+    for child_id in child_ids {
+        if let Some(group) = group {
+            groups.insert(child_id, group);
+        }
+        let child_group = if is_group(rdom, child_id) {
+            Some(child_id)
+        } else {
+            group
+        };
+        collect_groups(rdom, child_id, child_group, groups);
+    }
+}
+
+struct LayersRenderer<'a, RenderHook, GroupHook> {
+    viewports_collection: &'a ViewportsCollection,
+    dom: &'a FreyaDOM,
+    groups: FxHashMap<NodeId, NodeId>,
+    /// Nodes whose closest group is the key, ordered by layer.
+    members: FxHashMap<NodeId, Vec<NodeId>>,
+    render_hook: RenderHook,
+    group_hook: GroupHook,
+}
+
+impl<'a, RenderHook, GroupHook> LayersRenderer<'a, RenderHook, GroupHook> {
+    fn render_node<HookOptions>(
+        &self,
+        node_id: &NodeId,
+        font_collection: &mut FontCollection,
+        hook_options: &mut HookOptions,
+    ) where
+        RenderHook: Fn(
+            &FreyaDOM,
+            &NodeId,
+            &Area,
+            &mut FontCollection,
+            &ViewportsCollection,
+            &mut HookOptions,
+        ),
+        GroupHook: Fn(&FreyaDOM, &NodeId, RenderGroup, &ViewportsCollection, &mut HookOptions),
+    {
+        if !is_group(self.dom.rdom(), *node_id) {
+            self.render_element(node_id, font_collection, hook_options);
+            return;
+        }
+
+        (self.group_hook)(
+            self.dom,
+            node_id,
+            RenderGroup::Start,
+            self.viewports_collection,
+            hook_options,
+        );
+
+        self.render_element(node_id, font_collection, hook_options);
+
+        // Render the descendants of this group from the bottom to the top
+        if let Some(members) = self.members.get(node_id) {
+            for descendant_id in members {
+                self.render_node(descendant_id, font_collection, hook_options);
+            }
+        }
+
+        (self.group_hook)(
+            self.dom,
+            node_id,
+            RenderGroup::End,
+            self.viewports_collection,
+            hook_options,
+        );
+    }
+
+    fn render_element<HookOptions>(
+        &self,
+        node_id: &NodeId,
+        font_collection: &mut FontCollection,
+        hook_options: &mut HookOptions,
+    ) where
+        RenderHook: Fn(
+            &FreyaDOM,
+            &NodeId,
+            &Area,
+            &mut FontCollection,
+            &ViewportsCollection,
+            &mut HookOptions,
+        ),
+    {
+        let viewports = self.viewports_collection.get(node_id);
+        let layout = self.dom.layout();
+        let areas = layout.get(*node_id);
+
+        if let Some(areas) = areas {
+            // Skip elements that are completely out of any their parent's viewport
+            if let Some((_, viewports)) = viewports {
+                for viewport_id in viewports {
+                    let viewport = self.viewports_collection.get(viewport_id).unwrap().0;
+                    if let Some(viewport) = viewport {
+                        if !viewport.intersects(&areas.area) {
+                            return;
                         }
                     }
                 }
-
-                // Render the element
-                render_hook(
-                    dom,
-                    node_id,
-                    &areas.area,
-                    font_collection,
-                    viewports_collection,
-                    hook_options,
-                )
             }
+
+            // Render the element
+            (self.render_hook)(
+                self.dom,
+                node_id,
+                &areas.area,
+                font_collection,
+                self.viewports_collection,
+                hook_options,
+            )
         }
     }
 }
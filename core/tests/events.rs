@@ -1,8 +1,15 @@
 use dioxus::prelude::*;
 use freya_core::prelude::*;
 use freya_elements::elements as dioxus_elements;
-use freya_testing::{launch_test, MouseButton};
+use freya_elements::events::keyboard::{Code, Key, Modifiers};
+use freya_elements::events::{FileEvent, KeyboardEvent, MouseEvent, PointerEvent};
+use freya_testing::{
+    launch_test, launch_test_with_config, MouseButton, TestingConfig, TestingHandler,
+};
+use std::time::Duration;
+use tokio::time::sleep;
 use torin::prelude::CursorPoint;
+use winit::event::TouchPhase;
 
 #[tokio::test]
 pub async fn pointer_events() {
@@ -70,7 +77,7 @@ pub async fn pointer_events() {
     );
 
     utils.push_event(FreyaEvent::Mouse {
-        name: "click".to_string(),
+        name: "mouseup".to_string(),
         cursor: CursorPoint::new(100.0, 100.0),
         button: Some(MouseButton::Left),
     });
@@ -91,3 +98,1059 @@ pub async fn pointer_events() {
         Some(format!("{:?}", vec!["enter", "over", "down", "up", "leave"]).as_str())
     );
 }
+
+#[tokio::test]
+pub async fn shapes_hit_testing() {
+    fn shapes_app(cx: Scope) -> Element {
+        let clicks = use_state(cx, || 0);
+
+        render!(
+            circle {
+                width: "100",
+                height: "100",
+                onclick: move |_| clicks.with_mut(|clicks| *clicks += 1),
+            }
+            label { "{clicks}" }
+        )
+    }
+
+    let mut utils = launch_test(shapes_app);
+
+    let root = utils.root();
+    let label = root.get(1);
+
+    // The corner is inside the element but outside of the circle
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(5.0, 5.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("0"));
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("1"));
+}
+
+#[tokio::test]
+pub async fn interaction_states() {
+    fn interaction_states_app(cx: Scope) -> Element {
+        render!(rect {
+            width: "100",
+            height: "100",
+            background: "white",
+            hover_background: "gray",
+            pressed_background: "black",
+            focus_border: "2 blue",
+            focusable: "true",
+        })
+    }
+
+    let mut utils = launch_test(interaction_states_app);
+
+    let rect = utils.root().get(0);
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mouseover".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: None,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        utils.interaction_state(rect.id()),
+        InteractionState {
+            hovered: true,
+            pressed: false,
+            focused: false
+        }
+    );
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        utils.interaction_state(rect.id()),
+        InteractionState {
+            hovered: true,
+            pressed: true,
+            focused: true
+        }
+    );
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mouseover".to_string(),
+        cursor: CursorPoint::new(150.0, 150.0),
+        button: None,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        utils.interaction_state(rect.id()),
+        InteractionState {
+            hovered: false,
+            pressed: false,
+            focused: true
+        }
+    );
+}
+
+#[tokio::test]
+pub async fn event_bubbling() {
+    fn bubbling_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| state.with_mut(|v| v.push("outer")),
+                onclickcapture: move |_| state.with_mut(|v| v.push("outer capture")),
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    onclick: move |_| state.with_mut(|v| v.push("inner")),
+                    onclickcapture: move |_| state.with_mut(|v| v.push("inner capture")),
+                }
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(bubbling_app);
+
+    let label = utils.root().get(0).get(1);
+
+    // The capture phase goes from the root to the target, and then it bubbles back to the root
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec!["outer capture", "inner capture", "inner", "outer"]
+            )
+            .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+pub async fn overlapping_sibling_without_listener() {
+    fn overlapping_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| state.with_mut(|v| v.push("root")),
+                rect {
+                    height: "0",
+                    width: "100",
+                    onclick: move |_| state.with_mut(|v| v.push("wrapper")),
+                    onclickcapture: move |_| state.with_mut(|v| v.push("wrapper capture")),
+                    rect {
+                        height: "100",
+                        width: "100",
+                    }
+                }
+                rect {
+                    height: "100",
+                    width: "100",
+                    onclick: move |_| state.with_mut(|v| v.push("button")),
+                }
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(overlapping_app);
+
+    let label = utils.root().get(0).get(2);
+
+    // The overlay is on top of the button, so it's the target even if it doesn't listen to clicks
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["wrapper capture", "wrapper", "root"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn stop_propagation() {
+    fn stop_propagation_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| state.with_mut(|v| v.push("outer")),
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    onclick: move |e: MouseEvent| {
+                        e.data.stop_propagation();
+                        state.with_mut(|v| v.push("inner"))
+                    },
+                }
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(stop_propagation_app);
+
+    let label = utils.root().get(0).get(1);
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["inner"]).as_str())
+    );
+
+    // Clicking outside of the inner element only reaches the outer one
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 400.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["inner", "outer"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn stop_propagation_on_capture() {
+    fn capture_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onmousedowncapture: move |e: MouseEvent| {
+                    e.data.stop_propagation();
+                    state.with_mut(|v| v.push("outer capture"))
+                },
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    onmousedown: move |_| state.with_mut(|v| v.push("inner")),
+                }
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(capture_app);
+
+    let label = utils.root().get(0).get(1);
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["outer capture"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn prevent_default() {
+    fn prevent_default_app(cx: Scope) -> Element {
+        let default_prevented = use_state(cx, || false);
+
+        render!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |e: MouseEvent| default_prevented.set(e.data.is_default_prevented()),
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    onclick: move |e: MouseEvent| e.data.prevent_default(),
+                }
+                label { "{default_prevented}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(prevent_default_app);
+
+    let label = utils.root().get(0).get(1);
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 400.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("false"));
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("true"));
+}
+
+fn tab(shift: bool) -> FreyaEvent {
+    FreyaEvent::Keyboard {
+        name: "keydown".to_string(),
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: if shift {
+            Modifiers::SHIFT
+        } else {
+            Modifiers::empty()
+        },
+    }
+}
+
+#[tokio::test]
+pub async fn tab_navigation() {
+    fn tab_navigation_app(cx: Scope) -> Element {
+        render!(
+            rect { width: "100%", height: "20%", focusable: "true" }
+            rect { width: "100%", height: "20%", tab_index: "2" }
+            rect { width: "100%", height: "20%", tab_index: "-1" }
+            rect { width: "100%", height: "20%", tab_index: "1" }
+            rect { width: "100%", height: "20%" }
+        )
+    }
+
+    let mut utils = launch_test(tab_navigation_app);
+
+    let root = utils.root();
+    let first = root.get(0).id();
+    let second = root.get(1).id();
+    let third = root.get(2).id();
+    let fourth = root.get(3).id();
+
+    // Positive indexes go first, and then the rest in the DOM order
+    for expected in [fourth, second, first, fourth] {
+        utils.push_event(tab(false));
+        utils.wait_for_update().await;
+        assert_eq!(utils.focused_node(), Some(expected));
+    }
+
+    utils.push_event(tab(true));
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), Some(first));
+
+    // Negative indexes can still be focused by pressing them
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 250.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), Some(third));
+
+    // Pressing an element that is not focusable removes the focus
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 450.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), None);
+}
+
+#[tokio::test]
+pub async fn prevent_default_keeps_focus() {
+    fn prevent_focus_app(cx: Scope) -> Element {
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onkeydown: |e: KeyboardEvent| e.data.prevent_default(),
+                rect { width: "100%", height: "50%", focusable: "true" }
+                rect {
+                    width: "100%",
+                    height: "50%",
+                    focusable: "true",
+                    onmousedown: |e: MouseEvent| e.data.prevent_default(),
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(prevent_focus_app);
+
+    let first = utils.root().get(0).get(0).id();
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 100.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), Some(first));
+
+    // The root prevents Tab from moving the focus
+    utils.push_event(tab(false));
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), Some(first));
+
+    // And the second element prevents being focused when pressed
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 400.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), Some(first));
+}
+
+#[tokio::test]
+pub async fn focus_events() {
+    fn focus_events_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                width: "100%",
+                height: "50%",
+                focusable: "true",
+                onfocus: move |_| state.with_mut(|v| v.push("focus first")),
+                onblur: move |_| state.with_mut(|v| v.push("blur first")),
+            }
+            rect {
+                width: "100%",
+                height: "50%",
+                focusable: "true",
+                onfocus: move |_| state.with_mut(|v| v.push("focus second")),
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(focus_events_app);
+
+    let label = utils.root().get(1).get(0);
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["focus first"]).as_str())
+    );
+
+    utils.push_event(tab(false));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["focus first", "blur first", "focus second"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn keyboard_events_target_focused_element() {
+    fn keyboard_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onkeydown: move |_| state.with_mut(|v| v.push("outer")),
+                rect {
+                    width: "100%",
+                    height: "50%",
+                    focusable: "true",
+                    onkeydown: move |_| state.with_mut(|v| v.push("first")),
+                }
+                rect {
+                    width: "100%",
+                    height: "50%",
+                    focusable: "true",
+                    onkeydown: move |e: KeyboardEvent| {
+                        e.data.stop_propagation();
+                        state.with_mut(|v| v.push("second"))
+                    },
+                    label { "{state:?}" }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(keyboard_app);
+
+    let label = utils.root().get(0).get(1).get(0);
+
+    let key_a = FreyaEvent::Keyboard {
+        name: "keydown".to_string(),
+        key: Key::Character("a".to_string()),
+        code: Code::KeyA,
+        modifiers: Modifiers::empty(),
+    };
+
+    // The focused element gets the event, and then it bubbles to its ancestors
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.push_event(key_a.clone());
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["first", "outer"]).as_str())
+    );
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 400.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.push_event(key_a);
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["first", "outer", "second"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn click_events() {
+    fn click_events_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                height: "50%",
+                width: "100%",
+                onclick: move |_| state.with_mut(|v| v.push("click")),
+                onmouseup: move |_| state.with_mut(|v| v.push("mouseup")),
+                oncontextmenu: move |_| state.with_mut(|v| v.push("contextmenu")),
+                onmiddleclick: move |_| state.with_mut(|v| v.push("middleclick")),
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(click_events_app);
+
+    let label = utils.root().get(1).get(0);
+
+    let mouse = |name: &str, y: f64, button: MouseButton| FreyaEvent::Mouse {
+        name: name.to_string(),
+        cursor: CursorPoint::new(50.0, y),
+        button: Some(button),
+    };
+
+    // Pressing outside and releasing inside doesn't click
+    utils.push_event(mouse("mousedown", 400.0, MouseButton::Left));
+    utils.push_event(mouse("mouseup", 50.0, MouseButton::Left));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["mouseup"]).as_str())
+    );
+
+    utils.push_event(mouse("mousedown", 50.0, MouseButton::Left));
+    utils.wait_for_update().await;
+    utils.push_event(mouse("mouseup", 50.0, MouseButton::Left));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["mouseup", "mouseup", "click"]).as_str())
+    );
+
+    // Other buttons have their own events
+    for button in [MouseButton::Right, MouseButton::Middle] {
+        utils.push_event(mouse("mousedown", 50.0, button));
+        utils.push_event(mouse("mouseup", 50.0, button));
+        utils.wait_for_update().await;
+    }
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec![
+                    "mouseup",
+                    "mouseup",
+                    "click",
+                    "mouseup",
+                    "contextmenu",
+                    "mouseup",
+                    "middleclick"
+                ]
+            )
+            .as_str()
+        )
+    );
+}
+
+#[tokio::test]
+pub async fn double_click() {
+    fn double_click_app(cx: Scope) -> Element {
+        let clicks = use_state(cx, || 0);
+        let double_clicks = use_state(cx, || 0);
+
+        render!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| clicks += 1,
+                ondoubleclick: move |_| double_clicks += 1,
+                label { "{clicks} {double_clicks}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        double_click_app,
+        TestingConfig::default().with_double_click_interval(Duration::from_millis(200)),
+    );
+
+    let label = utils.root().get(0).get(0);
+
+    let click = |utils: &mut TestingHandler| {
+        for name in ["mousedown", "mouseup"] {
+            utils.push_event(FreyaEvent::Mouse {
+                name: name.to_string(),
+                cursor: CursorPoint::new(50.0, 50.0),
+                button: Some(MouseButton::Left),
+            });
+        }
+    };
+
+    click(&mut utils);
+    utils.wait_for_update().await;
+    click(&mut utils);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("2 1"));
+
+    // Clicks that are too far apart in time are not a double click
+    click(&mut utils);
+    utils.wait_for_update().await;
+    sleep(Duration::from_millis(300)).await;
+    click(&mut utils);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("4 1"));
+}
+
+#[tokio::test]
+pub async fn touch_click() {
+    fn touch_click_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onpointerdown: move |_| state.with_mut(|v| v.push("pointerdown")),
+                onpointerup: move |_| state.with_mut(|v| v.push("pointerup")),
+                onclick: move |_| state.with_mut(|v| v.push("click")),
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(touch_click_app);
+
+    let label = utils.root().get(0).get(0);
+
+    for (name, phase) in [
+        ("touchstart", TouchPhase::Started),
+        ("touchend", TouchPhase::Ended),
+    ] {
+        utils.push_event(FreyaEvent::Touch {
+            name: name.to_string(),
+            location: CursorPoint::new(50.0, 50.0),
+            finger_id: 0,
+            phase,
+            force: None,
+        });
+        utils.wait_for_update().await;
+    }
+
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["pointerdown", "pointerup", "click"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn pointer_capture() {
+    fn pointer_capture_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                height: "50%",
+                width: "100%",
+                onpointerdown: move |e: PointerEvent| {
+                    e.data.set_pointer_capture();
+                    state.with_mut(|v| v.push("down"))
+                },
+                onpointerover: move |_| state.with_mut(|v| v.push("over")),
+                onpointerup: move |_| state.with_mut(|v| v.push("up")),
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                onpointerover: move |_| state.with_mut(|v| v.push("other over")),
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(pointer_capture_app);
+
+    let label = utils.root().get(1).get(0);
+
+    let mouse = |name: &str, y: f64| FreyaEvent::Mouse {
+        name: name.to_string(),
+        cursor: CursorPoint::new(50.0, y),
+        button: Some(MouseButton::Left),
+    };
+
+    utils.push_event(mouse("mousedown", 50.0));
+    utils.wait_for_update().await;
+    assert_eq!(utils.pointer_capture(), Some(utils.root().get(0).id()));
+
+    // Moves and releases outside of the element still reach it while it captures the pointer
+    utils.push_event(mouse("mouseover", 400.0));
+    utils.wait_for_update().await;
+    utils.push_event(mouse("mouseup", 400.0));
+    utils.wait_for_update().await;
+    assert_eq!(utils.pointer_capture(), None);
+
+    utils.push_event(mouse("mouseover", 450.0));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["down", "over", "up", "other over"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn prune_removed_nodes() {
+    fn prune_app(cx: Scope) -> Element {
+        let show = use_state(cx, || true);
+
+        render!(if *show.get() {
+            rsx!(rect {
+                width: "100%",
+                height: "50%",
+                focusable: "true",
+                hover_background: "gray",
+                onkeydown: move |_| show.set(false),
+            })
+        })
+    }
+
+    let mut utils = launch_test(prune_app);
+
+    let rect_id = utils.root().get(0).id();
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mouseover".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: None,
+    });
+    utils.push_event(FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), Some(rect_id));
+    assert!(utils.interaction_state(rect_id).hovered);
+
+    // Removing the element without moving the cursor forgets its states
+    utils.push_event(FreyaEvent::Keyboard {
+        name: "keydown".to_string(),
+        key: Key::Enter,
+        code: Code::Enter,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.focused_node(), None);
+    assert_eq!(
+        utils.interaction_state(rect_id),
+        InteractionState::default()
+    );
+}
+
+#[tokio::test]
+pub async fn window_leave() {
+    fn window_leave_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onmouseenter: move |_| state.with_mut(|v| v.push("enter")),
+                onmouseleave: move |_| state.with_mut(|v| v.push("leave")),
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(window_leave_app);
+
+    let label = utils.root().get(0).get(0);
+
+    let mouse = |name: &str| FreyaEvent::Mouse {
+        name: name.to_string(),
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: None,
+    };
+
+    utils.push_event(mouse("mouseover"));
+    utils.wait_for_update().await;
+
+    // Leaving the window leaves the hovered elements, even if the cursor was last seen over them
+    utils.push_event(mouse("mouseleave"));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["enter", "leave"]).as_str())
+    );
+
+    utils.push_event(mouse("mouseover"));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["enter", "leave", "enter"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn hit_test_transforms_and_radius() {
+    fn hit_test_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                padding: "30",
+                rect {
+                    width: "100",
+                    height: "100",
+                    rotate: "45",
+                    onmousedown: move |_| state.with_mut(|v| v.push("rotated")),
+                }
+                rect {
+                    width: "100",
+                    height: "100",
+                    radius: "50",
+                    onmousedown: move |_| state.with_mut(|v| v.push("rounded")),
+                }
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(hit_test_app);
+
+    let label = utils.root().get(0).get(2);
+
+    let mousedown = |x: f64, y: f64| FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(x, y),
+        button: Some(MouseButton::Left),
+    };
+
+    // The corner of the layout area is outside the rotated element
+    utils.push_event(mousedown(35.0, 35.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[]"));
+
+    // The rotated element is hit outside of its layout area
+    utils.push_event(mousedown(80.0, 15.0));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["rotated"]).as_str())
+    );
+
+    // Rounded corners are not hit
+    utils.push_event(mousedown(32.0, 132.0));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["rotated"]).as_str())
+    );
+
+    utils.push_event(mousedown(80.0, 180.0));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["rotated", "rounded"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn pointer_events_none() {
+    fn pointer_events_none_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        render!(
+            rect {
+                width: "100",
+                height: "100",
+                onmousedown: move |_| state.with_mut(|v| v.push("button")),
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    pointer_events: "none",
+                    onmousedown: move |_| state.with_mut(|v| v.push("overlay")),
+                    rect {
+                        width: "100%",
+                        height: "50%",
+                        onmousedown: move |_| state.with_mut(|v| v.push("inherited")),
+                    }
+                    rect {
+                        width: "100%",
+                        height: "50%",
+                        pointer_events: "auto",
+                        onmousedown: move |_| state.with_mut(|v| v.push("auto")),
+                    }
+                }
+            }
+            label { "{state:?}" }
+        )
+    }
+
+    let mut utils = launch_test(pointer_events_none_app);
+
+    let label = utils.root().get(1);
+
+    let mousedown = |y: f64| FreyaEvent::Mouse {
+        name: "mousedown".to_string(),
+        cursor: CursorPoint::new(50.0, y),
+        button: Some(MouseButton::Left),
+    };
+
+    // The overlay and its children are skipped, so the element underneath is the target
+    utils.push_event(mousedown(25.0));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["button"]).as_str())
+    );
+
+    // Children can opt back in, and their events still bubble through their ancestors
+    utils.push_event(mousedown(75.0));
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", vec!["button", "auto", "overlay", "button"]).as_str())
+    );
+}
+
+#[tokio::test]
+pub async fn file_events() {
+    fn file_events_app(cx: Scope) -> Element {
+        let state = use_state(cx, Vec::new);
+
+        let onfilehover = move |e: FileEvent| {
+            let file_path = e.get_file_path().unwrap().display().to_string();
+            state.with_mut(|v| v.push(format!("hover {file_path}")))
+        };
+
+        let onfiledrop = move |e: FileEvent| {
+            let file_path = e.get_file_path().unwrap().display().to_string();
+            let cursor = e.get_element_coordinates();
+            state.with_mut(|v| v.push(format!("drop {file_path} {}:{}", cursor.x, cursor.y)))
+        };
+
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                padding: "50",
+                rect {
+                    width: "100",
+                    height: "100",
+                    onfilehover: onfilehover,
+                    onfiledrop: onfiledrop,
+                    onfilehovercancel: move |_| state.with_mut(|v| v.push("cancel".to_string())),
+                }
+                label { "{state:?}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(file_events_app);
+
+    let label = utils.root().get(0).get(1);
+
+    // Files are hit-tested like the mouse, so dropping outside of the zone does nothing
+    utils.drop_file(CursorPoint::new(10.0, 10.0), "outside.png");
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[]"));
+
+    utils.hover_file(CursorPoint::new(60.0, 70.0), "image.png");
+    utils.wait_for_update().await;
+
+    // The cancel reaches the hovered zone even if the cursor is elsewhere
+    utils.cancel_file_hover(CursorPoint::new(10.0, 10.0));
+    utils.wait_for_update().await;
+
+    utils.drop_file(CursorPoint::new(60.0, 70.0), "image.png");
+    utils.wait_for_update().await;
+    assert_eq!(
+        label.get(0).text(),
+        Some(
+            format!(
+                "{:?}",
+                vec!["hover image.png", "cancel", "drop image.png 10:20"]
+            )
+            .as_str()
+        )
+    );
+}
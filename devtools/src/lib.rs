@@ -10,8 +10,9 @@ use freya_core::node::{get_node_state, NodeState};
 use freya_dom::prelude::SafeDOM;
 use freya_elements::elements as dioxus_elements;
 use freya_hooks::use_theme;
+use freya_node_state::AttributeDiagnostic;
 
-use freya_renderer::HoveredNode;
+use freya_renderer::{HoveredNode, SharedAssetsCacheStats};
 use std::sync::Arc;
 use tokio::sync::Notify;
 use torin::prelude::NodeAreas;
@@ -22,7 +23,7 @@ mod tab;
 mod tabs;
 
 use tab::*;
-use tabs::{layout::*, style::*, tree::*};
+use tabs::{cache::*, layout::*, problems::*, style::*, tree::*};
 
 /// Run the [`VirtualDom`](dioxus_core::VirtualDom) with a sidepanel where the devtools are located.
 pub fn with_devtools(
@@ -30,6 +31,7 @@ pub fn with_devtools(
     root: fn(cx: Scope) -> Element,
     mutations_notifier: Arc<Notify>,
     hovered_node: HoveredNode,
+    assets_cache_stats: SharedAssetsCacheStats,
 ) -> VirtualDom {
     VirtualDom::new_with_props(
         AppWithDevtools,
@@ -38,6 +40,7 @@ pub fn with_devtools(
             rdom,
             mutations_notifier,
             hovered_node,
+            assets_cache_stats,
         },
     )
 }
@@ -47,6 +50,7 @@ struct AppWithDevtoolsProps {
     rdom: SafeDOM,
     mutations_notifier: Arc<Notify>,
     hovered_node: HoveredNode,
+    assets_cache_stats: SharedAssetsCacheStats,
 }
 
 #[allow(non_snake_case)]
@@ -55,6 +59,7 @@ fn AppWithDevtools(cx: Scope<AppWithDevtoolsProps>) -> Element {
     let Root = cx.props.root;
     let mutations_notifier = cx.props.mutations_notifier.clone();
     let hovered_node = cx.props.hovered_node.clone();
+    let assets_cache_stats = cx.props.assets_cache_stats.clone();
 
     render!(
         rect {
@@ -75,6 +80,7 @@ fn AppWithDevtools(cx: Scope<AppWithDevtoolsProps>) -> Element {
                         rdom: cx.props.rdom.clone(),
                         mutations_notifier: mutations_notifier
                         hovered_node: hovered_node
+                        assets_cache_stats: assets_cache_stats
                     }
                 }
             }
@@ -98,6 +104,7 @@ pub struct DevToolsProps {
     rdom: SafeDOM,
     mutations_notifier: Arc<Notify>,
     hovered_node: HoveredNode,
+    assets_cache_stats: SharedAssetsCacheStats,
 }
 
 impl PartialEq for DevToolsProps {
@@ -109,6 +116,7 @@ impl PartialEq for DevToolsProps {
 #[allow(non_snake_case)]
 pub fn DevTools(cx: Scope<DevToolsProps>) -> Element {
     let children = use_state(cx, Vec::<TreeNode>::new);
+    let problems = use_state(cx, Vec::<AttributeDiagnostic>::new);
     let theme = use_theme(cx);
     let theme = theme.read();
 
@@ -117,6 +125,7 @@ pub fn DevTools(cx: Scope<DevToolsProps>) -> Element {
         let rdom = cx.props.rdom.clone();
         let mutations_notifier = cx.props.mutations_notifier.clone();
         let children = children.clone();
+        let problems = problems.clone();
         async move {
             loop {
                 mutations_notifier.notified().await;
@@ -166,7 +175,14 @@ pub fn DevTools(cx: Scope<DevToolsProps>) -> Element {
                         }
                     }
                 });
+
+                // Only show the problems of the app Nodes
+                let mut new_problems = dom.diagnostics().get();
+                new_problems
+                    .retain(|problem| new_children.iter().any(|child| child.id == problem.node_id));
+
                 children.set(new_children);
+                problems.set(new_problems);
             }
         }
     });
@@ -203,6 +219,25 @@ pub fn DevTools(cx: Scope<DevToolsProps>) -> Element {
                         }
                     }
                 }
+                Route {
+                    to: "/problems",
+                    NodesProblems {
+                        problems: problems,
+                        nodes: children,
+                        onselected: |node: &TreeNode| {
+                            if let Some(hovered_node) = &cx.props.hovered_node {
+                                hovered_node.lock().unwrap().replace(node.id);
+                            }
+                            selected_node_id.set(Some(node.id));
+                        }
+                    }
+                }
+                Route {
+                    to: "/cache",
+                    AssetsCacheInfo {
+                        stats: &cx.props.assets_cache_stats
+                    }
+                }
                 Route {
                     to: "/elements/style",
                     NodesTree {
@@ -258,6 +293,14 @@ pub fn DevtoolsBar(cx: Scope) -> Element {
                 to: "/elements",
                 label: "Elements"
             }
+            TabButton {
+                to: "/problems",
+                label: "Problems"
+            }
+            TabButton {
+                to: "/cache",
+                label: "Cache"
+            }
         }
     )
 }
@@ -1,6 +1,6 @@
 use dioxus::prelude::*;
 use dioxus_router::use_router;
-use freya_components::{ButtonStatus, ScrollView};
+use freya_components::ScrollView;
 use freya_elements::elements as dioxus_elements;
 use freya_hooks::use_get_theme;
 
@@ -31,35 +31,21 @@ pub struct TabButtonProps<'a> {
 pub fn TabButton<'a>(cx: Scope<'a, TabButtonProps<'a>>) -> Element<'a> {
     let router = use_router(cx);
     let theme = use_get_theme(cx);
-    let status = use_state(cx, ButtonStatus::default);
 
     let onclick = move |_| {
         router.replace_route(cx.props.to, None, None);
     };
 
-    let onmouseover = move |_| {
-        if *status.get() != ButtonStatus::Hovering {
-            status.set(ButtonStatus::Hovering);
-        }
-    };
-
-    let onmouseleave = move |_| {
-        status.set(ButtonStatus::default());
-    };
-
-    let background = match *status.get() {
-        ButtonStatus::Hovering => theme.button.hover_background,
-        ButtonStatus::Idle => theme.button.background,
-    };
+    let background = theme.button.background;
+    let hover_background = theme.button.hover_background;
     let color = theme.button.font_theme.color;
     let content = cx.props.label;
 
     render!(
         container {
             background: "{background}",
+            hover_background: "{hover_background}",
             onclick: onclick,
-            onmouseover: onmouseover,
-            onmouseleave: onmouseleave,
             width: "125",
             radius: "7",
             height: "100%",
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_components::*;
+use freya_elements::elements as dioxus_elements;
+use freya_renderer::{AssetsCacheStats, SharedAssetsCacheStats};
+
+#[allow(non_snake_case)]
+#[inline_props]
+pub fn AssetsCacheInfo<'a>(cx: Scope<'a>, stats: &'a SharedAssetsCacheStats) -> Element<'a> {
+    let current_stats = use_state(cx, AssetsCacheStats::default);
+
+    // The stats change while rendering, so they are checked periodically
+    use_effect(cx, (), move |_| {
+        let stats = (*stats).clone();
+        let current_stats = current_stats.clone();
+        async move {
+            loop {
+                let new_stats = *stats.lock().unwrap();
+                if new_stats != *current_stats.current() {
+                    current_stats.set(new_stats);
+                }
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    });
+
+    let AssetsCacheStats {
+        hits,
+        misses,
+        evictions,
+        entries,
+        size,
+    } = *current_stats.get();
+    let hit_ratio = if hits + misses > 0 {
+        hits as f32 / (hits + misses) as f32 * 100.0
+    } else {
+        0.0
+    };
+    let size = size as f32 / (1024.0 * 1024.0);
+
+    render!(
+        ScrollView {
+            show_scrollbar: true,
+            height: "calc(100% - 35)",
+            width: "100%",
+            padding: "20",
+            label {
+                height: "25",
+                "Images and SVGs cache"
+            }
+            label {
+                height: "25",
+                "Entries: {entries}"
+            }
+            label {
+                height: "25",
+                "Size: {size:.2} MB"
+            }
+            label {
+                height: "25",
+                "Hits: {hits}"
+            }
+            label {
+                height: "25",
+                "Misses: {misses}"
+            }
+            label {
+                height: "25",
+                "Hit ratio: {hit_ratio:.1}%"
+            }
+            label {
+                height: "25",
+                "Evictions: {evictions}"
+            }
+        }
+    )
+}
@@ -1,3 +1,5 @@
+pub mod cache;
 pub mod layout;
+pub mod problems;
 pub mod style;
 pub mod tree;
@@ -0,0 +1,66 @@
+use dioxus::prelude::*;
+use dioxus_router::*;
+use freya_components::*;
+use freya_elements::elements as dioxus_elements;
+use freya_node_state::AttributeDiagnostic;
+
+use crate::TreeNode;
+
+#[allow(non_snake_case)]
+#[inline_props]
+pub fn NodesProblems<'a>(
+    cx: Scope<'a>,
+    problems: &'a Vec<AttributeDiagnostic>,
+    nodes: &'a Vec<TreeNode>,
+    onselected: EventHandler<'a, &'a TreeNode>,
+) -> Element<'a> {
+    let router = use_router(cx);
+
+    if problems.is_empty() {
+        return render!(
+            rect {
+                width: "100%",
+                height: "calc(100% - 35)",
+                padding: "20",
+                label {
+                    "No problems found."
+                }
+            }
+        );
+    }
+
+    render!(
+        ScrollView {
+            show_scrollbar: true,
+            height: "calc(100% - 35)",
+            width: "100%",
+            padding: "15",
+            problems.iter().enumerate().filter_map(|(i, problem)| {
+                let node = nodes.iter().find(|node| node.id == problem.node_id)?;
+                Some(rsx!(
+                    rect {
+                        key: "{i}",
+                        width: "100%",
+                        height: "55",
+                        padding: "5",
+                        radius: "7",
+                        onmousedown: move |_| {
+                            onselected.call(node);
+                            router.replace_route("/elements/style", None, None)
+                        },
+                        label {
+                            font_size: "14",
+                            color: "rgb(240, 90, 90)",
+                            "{problem.reason}: <{problem.attribute}> \"{problem.value}\""
+                        }
+                        label {
+                            font_size: "13",
+                            color: "rgb(180, 180, 180)",
+                            "{node.tag} #{node.id:?}"
+                        }
+                    }
+                ))
+            })
+        }
+    )
+}
@@ -7,12 +7,14 @@ use dioxus_native_core::{
     NodeId, SendAnyMap,
 };
 use freya_node_state::{
-    CursorSettings, CustomAttributeValues, FontStyle, References, SizeState, Style, Transform,
+    AttributeDiagnostics, CursorSettings, CustomAttributeValues, Focus, FontStyle, MatchedStyles,
+    PointerEventsState, References, Shape, SizeState, Style, StylesheetState, Transform,
+    TransitionState, UnsupportedAttributes,
 };
 use std::sync::MutexGuard;
 use torin::prelude::*;
 
-use crate::dom_adapter::DioxusDOMAdapter;
+use crate::dom_adapter::{DioxusDOMAdapter, SizeOverrides};
 
 pub type DioxusDOM = RealDom<CustomAttributeValues>;
 pub type DioxusNode<'a> = NodeRef<'a, CustomAttributeValues>;
@@ -79,23 +81,34 @@ pub struct FreyaDOM {
     rdom: DioxusDOM,
     dioxus_integration_state: DioxusState,
     torin: Arc<Mutex<Torin<NodeId>>>,
+    diagnostics: AttributeDiagnostics,
+    size_overrides: SizeOverrides,
 }
 
 impl Default for FreyaDOM {
     fn default() -> Self {
         let mut rdom = RealDom::<CustomAttributeValues>::new([
             CursorSettings::to_type_erased(),
+            Focus::to_type_erased(),
             FontStyle::to_type_erased(),
+            MatchedStyles::to_type_erased(),
+            PointerEventsState::to_type_erased(),
             References::to_type_erased(),
+            Shape::to_type_erased(),
             SizeState::to_type_erased(),
             Style::to_type_erased(),
+            StylesheetState::to_type_erased(),
             Transform::to_type_erased(),
+            TransitionState::to_type_erased(),
+            UnsupportedAttributes::to_type_erased(),
         ]);
         let dioxus_integration_state = DioxusState::create(&mut rdom);
         Self {
             rdom,
             dioxus_integration_state,
             torin: Arc::new(Mutex::new(Torin::new())),
+            diagnostics: AttributeDiagnostics::default(),
+            size_overrides: SizeOverrides::default(),
         }
     }
 }
@@ -106,6 +119,8 @@ impl FreyaDOM {
             rdom,
             dioxus_integration_state,
             torin: Arc::new(Mutex::new(Torin::new())),
+            diagnostics: AttributeDiagnostics::default(),
+            size_overrides: SizeOverrides::default(),
         }
     }
 
@@ -113,6 +128,21 @@ impl FreyaDOM {
         self.torin.lock().unwrap()
     }
 
+    /// Get the sizes that replace the ones of some Nodes while measuring.
+    pub fn size_overrides(&self) -> &SizeOverrides {
+        &self.size_overrides
+    }
+
+    /// Replace the sizes of some Nodes while measuring, e.g for transitions.
+    pub fn set_size_overrides(&mut self, size_overrides: SizeOverrides) {
+        self.size_overrides = size_overrides;
+    }
+
+    /// Get the problems found while applying the Nodes attributes.
+    pub fn diagnostics(&self) -> &AttributeDiagnostics {
+        &self.diagnostics
+    }
+
     /// Create the initial DOM from the given Mutations
     pub fn init_dom(&mut self, mutations: Mutations, scale_factor: f32) {
         self.dioxus_integration_state
@@ -121,6 +151,7 @@ impl FreyaDOM {
         let mut ctx = SendAnyMap::new();
         ctx.insert(scale_factor);
         ctx.insert(self.torin.clone());
+        ctx.insert(self.diagnostics.clone());
 
         self.rdom.update_state(ctx);
     }
@@ -140,10 +171,15 @@ impl FreyaDOM {
         let mut ctx = SendAnyMap::new();
         ctx.insert(scale_factor);
         ctx.insert(self.torin.clone());
+        ctx.insert(self.diagnostics.clone());
 
         // Update the Node's states
         let (_, diff) = self.rdom.update_state(ctx);
 
+        // Forget the diagnostics of removed Nodes
+        self.diagnostics
+            .retain_nodes(|node_id| self.rdom.get(node_id).is_some());
+
         let must_repaint = !diff.is_empty();
         let must_relayout = !self.layout().get_dirty_nodes().is_empty();
 
@@ -1,17 +1,37 @@
 use dioxus_native_core::{prelude::NodeType, real_dom::NodeImmutable, tree::TreeRef, NodeId};
 use freya_node_state::SizeState;
+use rustc_hash::FxHashMap;
 use torin::prelude::*;
 
 use crate::dom::DioxusDOM;
 
+/// Sizes that replace the ones from the [`SizeState`] of a Node while measuring, e.g for transitions.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct SizeOverride {
+    pub width: Option<Size>,
+    pub height: Option<Size>,
+}
+
+pub type SizeOverrides = FxHashMap<NodeId, SizeOverride>;
+
 /// RealDOM adapter for Torin.
 pub struct DioxusDOMAdapter<'a> {
     pub rdom: &'a DioxusDOM,
+    pub size_overrides: Option<&'a SizeOverrides>,
 }
 
 impl<'a> DioxusDOMAdapter<'a> {
     pub fn new(rdom: &'a DioxusDOM) -> Self {
-        Self { rdom }
+        Self {
+            rdom,
+            size_overrides: None,
+        }
+    }
+
+    /// Measure the Nodes with the given [`SizeOverrides`].
+    pub fn with_size_overrides(mut self, size_overrides: &'a SizeOverrides) -> Self {
+        self.size_overrides = Some(size_overrides);
+        self
     }
 }
 
@@ -20,6 +40,18 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
         let node = self.rdom.get(*node_id)?;
         let mut size = node.get::<SizeState>().unwrap().clone();
 
+        if let Some(size_override) = self
+            .size_overrides
+            .and_then(|size_overrides| size_overrides.get(node_id))
+        {
+            if let Some(width) = &size_override.width {
+                size.width = width.clone();
+            }
+            if let Some(height) = &size_override.height {
+                size.height = height.clone();
+            }
+        }
+
         // The root node expands by default
         if *node_id == self.rdom.root_id() {
             size.width = Size::Percentage(Length::new(100.0));
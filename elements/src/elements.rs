@@ -18,12 +18,22 @@ macro_rules! impl_element_match {
     };
 }
 
+/// Name of an attribute in the DOM, attributes named after a keyword are renamed, e.g `r#loop` is `loop`.
+macro_rules! attribute_name {
+    ($fil:ident) => {
+        stringify!($fil)
+    };
+    ($fil:ident = $name:literal) => {
+        $name
+    };
+}
+
 macro_rules! impl_attribute_match {
     (
-        $attr:ident $fil:ident: $vil:ident,
+        $attr:ident $fil:ident: $vil:ident $(= $name:literal)?,
     ) => {
-        if $attr == stringify!($fil) {
-            return Some((stringify!($fil), None));
+        if $attr == stringify!($fil) || $attr == attribute_name!($fil $(= $name)?) {
+            return Some((attribute_name!($fil $(= $name)?), None));
         }
     };
 }
@@ -32,14 +42,14 @@ macro_rules! impl_element_match_attributes {
     (
         $el:ident $attr:ident $name:ident None {
             $(
-                $fil:ident: $vil:ident,
+                $fil:ident: $vil:ident $(= $attr_name:literal)?,
             )*
         }
     ) => {
         if $el == stringify!($name) {
             $(
                 impl_attribute_match!(
-                    $attr $fil: $vil,
+                    $attr $fil: $vil $(= $attr_name)?,
                 );
             )*
         }
@@ -48,14 +58,14 @@ macro_rules! impl_element_match_attributes {
     (
         $el:ident $attr:ident $name:ident  {
             $(
-                $fil:ident: $vil:ident,
+                $fil:ident: $vil:ident $(= $attr_name:literal)?,
             )*
         }
     ) => {
         if $el == stringify!($name) {
             $(
                 impl_attribute_match!(
-                    $attr $fil: $vil,
+                    $attr $fil: $vil $(= $attr_name)?,
                 );
             )*
         }
@@ -69,20 +79,34 @@ macro_rules! builder_constructors {
             $name:ident {
                 $(
                     $(#[$attr_method:meta])*
-                    $fil:ident: $vil:ident,
+                    $fil:ident: $vil:ident $(= $attr_name:literal)?,
                 )*
             };
          )*
         ) => {
         pub struct FreyaCtx;
 
+        /// Get the names in the DOM of the attributes supported by an element, `None` if the element doesn't exist.
+        pub fn element_attributes(element: &str) -> Option<&'static [&'static str]> {
+            $(
+                if element == stringify!($name) {
+                    return Some(&[
+                        $(
+                            attribute_name!($fil $(= $attr_name)?),
+                        )*
+                    ]);
+                }
+            )*
+            None
+        }
+
         impl HotReloadingContext for FreyaCtx {
             fn map_attribute(element: &str, attribute: &str) -> Option<(&'static str, Option<&'static str>)> {
                 $(
                     impl_element_match_attributes!(
                         element attribute $name {
                             $(
-                                $fil: $vil,
+                                $fil: $vil $(= $attr_name)?,
                             )*
                         }
                     );
@@ -110,7 +134,7 @@ macro_rules! builder_constructors {
                 $name {
                     $(
                         $(#[$attr_method])*
-                        $fil: $vil,
+                        $fil: $vil $(= $attr_name)?,
                     )*
                 };
             );
@@ -125,7 +149,7 @@ macro_rules! impl_element {
             $name:ident {
                 $(
                     $(#[$attr_method:meta])*
-                    $fil:ident: $vil:ident,
+                    $fil:ident: $vil:ident $(= $attr_name:literal)?,
                 )*
             };
          )*
@@ -143,7 +167,7 @@ macro_rules! impl_element {
 
                 $(
                     #[allow(non_upper_case_globals)]
-                    pub const $fil: AttributeDescription = (stringify!($fil), None, false);
+                    pub const $fil: AttributeDescription = (attribute_name!($fil $(= $attr_name)?), None, false);
                 )*
             }
 
@@ -174,6 +198,20 @@ builder_constructors! {
         cursor_reference: CursorReference,
         rotate: String,
         canvas_reference: String,
+        hover_background: String,
+        pressed_background: String,
+        focus_border: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
+        stylesheet: String,
     };
     container {
         padding: String,
@@ -196,6 +234,20 @@ builder_constructors! {
         cursor_reference: CursorReference,
         rotate: String,
         canvas_reference: String,
+        hover_background: String,
+        pressed_background: String,
+        focus_border: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
+        stylesheet: String,
     };
     label {
         color: String,
@@ -209,7 +261,24 @@ builder_constructors! {
         font_width: String,
         align: String,
         max_lines: String,
+        text_overflow: String,
         rotate: String,
+        decoration: String,
+        decoration_color: String,
+        decoration_style: String,
+        letter_spacing: String,
+        word_spacing: String,
+        text_shadow: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
     };
     paragraph {
         layer: String,
@@ -218,6 +287,7 @@ builder_constructors! {
         align: String,
         cursor_index: String,
         max_lines: String,
+        text_overflow: String,
         cursor_color: String,
         cursor_mode: String,
         line_height: String,
@@ -228,6 +298,22 @@ builder_constructors! {
         highlight_color: String,
         font_size: String,
         font_family: String,
+        decoration: String,
+        decoration_color: String,
+        decoration_style: String,
+        letter_spacing: String,
+        word_spacing: String,
+        text_shadow: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
     };
     text {
         color: String,
@@ -241,6 +327,14 @@ builder_constructors! {
         font_width: String,
         line_height: String,
         rotate: String,
+        decoration: String,
+        decoration_color: String,
+        decoration_style: String,
+        letter_spacing: String,
+        word_spacing: String,
+        text_shadow: String,
+        blend_mode: String,
+        class: String,
     };
     image {
         image_data: String,
@@ -248,6 +342,23 @@ builder_constructors! {
         width: String,
         height: String,
         rotate: String,
+        radius: String,
+        image_fit: String,
+        image_align: String,
+        sampling: String,
+        autoplay: String,
+        r#loop: String = "loop",
+        image_playback: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
     };
     svg {
         svg_data: String,
@@ -255,11 +366,130 @@ builder_constructors! {
         width: String,
         height: String,
         rotate: String,
+        fill: String,
+        stroke: String,
+        color: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
+    };
+    circle {
+        width: String,
+        height: String,
+        min_width: String,
+        min_height: String,
+        max_width: String,
+        max_height: String,
+        layer: String,
+        rotate: String,
+        fill: String,
+        stroke: String,
+        stroke_width: String,
+        stroke_dash: String,
+        color: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
+    };
+    line {
+        points: String,
+        width: String,
+        height: String,
+        min_width: String,
+        min_height: String,
+        max_width: String,
+        max_height: String,
+        layer: String,
+        rotate: String,
+        fill: String,
+        stroke: String,
+        stroke_width: String,
+        stroke_dash: String,
+        color: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
+    };
+    polygon {
+        points: String,
+        width: String,
+        height: String,
+        min_width: String,
+        min_height: String,
+        max_width: String,
+        max_height: String,
+        layer: String,
+        rotate: String,
+        fill: String,
+        stroke: String,
+        stroke_width: String,
+        stroke_dash: String,
+        color: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
+    };
+    path {
+        d: String,
+        width: String,
+        height: String,
+        min_width: String,
+        min_height: String,
+        max_width: String,
+        max_height: String,
+        layer: String,
+        rotate: String,
+        fill: String,
+        stroke: String,
+        stroke_width: String,
+        stroke_dash: String,
+        color: String,
+        blur: String,
+        backdrop_blur: String,
+        blend_mode: String,
+        transition: String,
+        animate_layout: String,
+        focusable: String,
+        tab_index: String,
+        focus_reference: String,
+        pointer_events: String,
+        class: String,
     };
 }
 
 pub mod events {
-    use crate::events::{KeyboardData, MouseData, PointerData, TouchData, WheelData};
+    use crate::events::{
+        CompositionData, FileData, FocusData, KeyboardData, MouseData, PointerData, TextInputData,
+        TouchData, WheelData,
+    };
 
     macro_rules! impl_event {
         (
@@ -290,16 +520,25 @@ pub mod events {
         onclick
         onglobalclick
         onmousedown
+        onmouseup
+        ondoubleclick
+        oncontextmenu
+        onmiddleclick
         onmouseover
         onglobalmouseover
         onmouseleave
         onmouseenter
+        onclickcapture
+        onmousedowncapture
+        onmouseupcapture
+        onmouseovercapture
     ];
 
     impl_event! [
         WheelData;
 
         onwheel
+        onwheelcapture
     ];
 
     impl_event! [
@@ -307,6 +546,23 @@ pub mod events {
 
         onkeydown
         onkeyup
+        onkeydowncapture
+        onkeyupcapture
+    ];
+
+    impl_event! [
+        CompositionData;
+
+        oncompositionstart
+        oncompositionupdate
+        oncompositionend
+    ];
+
+    impl_event! [
+        TextInputData;
+
+        ontextinput
+        ontextinputcapture
     ];
 
     impl_event! [
@@ -316,6 +572,10 @@ pub mod events {
         ontouchend
         ontouchmove
         ontouchstart
+        ontouchcancelcapture
+        ontouchendcapture
+        ontouchmovecapture
+        ontouchstartcapture
     ];
 
     impl_event! [
@@ -326,6 +586,24 @@ pub mod events {
         onpointerover
         onpointerenter
         onpointerleave
+        onpointerdowncapture
+        onpointerupcapture
+        onpointerovercapture
+    ];
+
+    impl_event! [
+        FileData;
+
+        onfiledrop
+        onfilehover
+        onfilehovercancel
+    ];
+
+    impl_event! [
+        FocusData;
+
+        onfocus
+        onblur
     ];
 }
 
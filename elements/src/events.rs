@@ -1,13 +1,23 @@
+pub mod composition;
+pub mod file;
+pub mod focus;
 pub mod keyboard;
 pub mod mouse;
 pub mod pointer;
+pub mod propagation;
+pub mod text_input;
 pub mod touch;
 pub mod wheel;
 
+pub use composition::CompositionData;
 use dioxus_core::Event;
+pub use file::FileData;
+pub use focus::FocusData;
 pub use keyboard::KeyboardData;
 pub use mouse::MouseData;
 pub use pointer::PointerData;
+pub use propagation::EventPropagation;
+pub use text_input::TextInputData;
 pub use touch::TouchData;
 pub use wheel::WheelData;
 
@@ -16,3 +26,7 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type FocusEvent = Event<FocusData>;
+pub type CompositionEvent = Event<CompositionData>;
+pub type TextInputEvent = Event<TextInputData>;
+pub type FileEvent = Event<FileData>;
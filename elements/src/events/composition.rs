@@ -0,0 +1,31 @@
+use crate::events::EventPropagation;
+
+/// Data of a Composition event, emitted while an input method composes some text.
+#[derive(Debug, Clone)]
+pub struct CompositionData {
+    data: String,
+    cursor: Option<(usize, usize)>,
+    pub(crate) propagation: EventPropagation,
+}
+
+impl CompositionData {
+    pub fn new(data: String, cursor: Option<(usize, usize)>) -> Self {
+        Self {
+            data,
+            cursor,
+            propagation: EventPropagation::default(),
+        }
+    }
+}
+
+impl CompositionData {
+    /// Get the text being composed, or the committed text in a `compositionend` event.
+    pub fn get_data(&self) -> &str {
+        &self.data
+    }
+
+    /// Get the byte range of the composed text where the input method cursor is, if any.
+    pub fn get_cursor(&self) -> Option<(usize, usize)> {
+        self.cursor
+    }
+}
@@ -0,0 +1,48 @@
+use std::path::PathBuf;
+
+use crate::events::EventPropagation;
+use torin::geometry::CursorPoint;
+
+/// Data of a File event, emitted when files are dragged from the OS over the window.
+#[derive(Debug, Clone)]
+pub struct FileData {
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub file_path: Option<PathBuf>,
+    pub(crate) propagation: EventPropagation,
+}
+
+impl FileData {
+    pub fn new(
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+        file_path: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            screen_coordinates,
+            element_coordinates,
+            file_path,
+            propagation: EventPropagation::default(),
+        }
+    }
+}
+
+impl FileData {
+    /// Get the cursor coordinates relative to the window bounds.
+    ///
+    /// Some platforms don't report the cursor while files are dragged over the window,
+    /// so it might be where the cursor was before the drag started.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the cursor coordinates relatives to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get the path of the dropped or hovered file, a `filehovercancel` event has none.
+    pub fn get_file_path(&self) -> Option<&PathBuf> {
+        self.file_path.as_ref()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::events::EventPropagation;
+
+/// Data of a Focus event.
+#[derive(Debug, Clone, Default)]
+pub struct FocusData {
+    pub(crate) propagation: EventPropagation,
+}
+
+impl FocusData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::events::EventPropagation;
 pub use keyboard_types::{Code, Key, Modifiers};
 use winit::event::{ModifiersState, VirtualKeyCode};
 
@@ -363,6 +364,7 @@ pub struct KeyboardData {
     pub key: Key,
     pub code: Code,
     pub modifiers: Modifiers,
+    pub(crate) propagation: EventPropagation,
 }
 
 impl KeyboardData {
@@ -371,6 +373,7 @@ impl KeyboardData {
             key,
             code,
             modifiers,
+            propagation: EventPropagation::default(),
         }
     }
 }
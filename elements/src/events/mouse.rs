@@ -1,3 +1,4 @@
+use crate::events::EventPropagation;
 use torin::geometry::CursorPoint;
 pub use winit::event::MouseButton;
 
@@ -7,6 +8,7 @@ pub struct MouseData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub trigger_button: Option<MouseButton>,
+    pub(crate) propagation: EventPropagation,
 }
 
 impl MouseData {
@@ -19,6 +21,7 @@ impl MouseData {
             screen_coordinates,
             element_coordinates,
             trigger_button,
+            propagation: EventPropagation::default(),
         }
     }
 }
@@ -1,3 +1,7 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use crate::events::EventPropagation;
 use torin::geometry::CursorPoint;
 pub use winit::event::MouseButton;
 use winit::event::{Force, TouchPhase};
@@ -15,12 +19,36 @@ pub enum PointerType {
     },
 }
 
+/// Captures or releases the pointer for the element that received a Pointer event.
+#[derive(Clone, Default)]
+pub struct PointerCapture(Option<Arc<dyn Fn(bool) + Send + Sync>>);
+
+impl PointerCapture {
+    pub fn new(capture: impl Fn(bool) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(capture)))
+    }
+
+    fn set(&self, captured: bool) {
+        if let Some(capture) = &self.0 {
+            capture(captured);
+        }
+    }
+}
+
+impl Debug for PointerCapture {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PointerCapture").finish_non_exhaustive()
+    }
+}
+
 /// Data of a Mouse event.
 #[derive(Debug, Clone)]
 pub struct PointerData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub point_type: PointerType,
+    pub(crate) propagation: EventPropagation,
+    capture: PointerCapture,
 }
 
 impl PointerData {
@@ -33,8 +61,16 @@ impl PointerData {
             screen_coordinates,
             element_coordinates,
             point_type,
+            propagation: EventPropagation::default(),
+            capture: PointerCapture::default(),
         }
     }
+
+    #[doc(hidden)]
+    pub fn with_pointer_capture(mut self, capture: PointerCapture) -> Self {
+        self.capture = capture;
+        self
+    }
 }
 
 impl PointerData {
@@ -52,4 +88,16 @@ impl PointerData {
     pub fn get_pointer_type(&self) -> PointerType {
         self.point_type
     }
+
+    /// Send all the following pointer moves and releases to the element that received this event,
+    /// even when they happen outside of it. The capture ends when the pointer is released
+    /// or with [`PointerData::release_pointer_capture`].
+    pub fn set_pointer_capture(&self) {
+        self.capture.set(true);
+    }
+
+    /// Stop capturing the pointer, if this element was capturing it.
+    pub fn release_pointer_capture(&self) {
+        self.capture.set(false);
+    }
 }
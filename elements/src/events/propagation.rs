@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::events::{
+    CompositionData, FileData, FocusData, KeyboardData, MouseData, PointerData, TextInputData,
+    TouchData, WheelData,
+};
+
+#[derive(Debug, Default)]
+struct PropagationState {
+    stopped: AtomicBool,
+    default_prevented: AtomicBool,
+}
+
+/// Propagation of an event through its capture, target and bubble phases.
+/// It is shared by all the listeners that receive the same event.
+#[derive(Debug, Clone, Default)]
+pub struct EventPropagation(Arc<PropagationState>);
+
+impl EventPropagation {
+    /// Stop the event from reaching the next listeners.
+    pub fn stop(&self) {
+        self.0.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if a listener stopped the event.
+    pub fn is_stopped(&self) -> bool {
+        self.0.stopped.load(Ordering::Relaxed)
+    }
+
+    /// Tell Freya and the next listeners that the default action of the event should not happen.
+    pub fn prevent_default(&self) {
+        self.0.default_prevented.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if a listener prevented the default action of the event.
+    pub fn is_default_prevented(&self) -> bool {
+        self.0.default_prevented.load(Ordering::Relaxed)
+    }
+
+    /// Check if two listeners received the same event, e.g while it was bubbling.
+    pub fn is_same_event(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// Dioxus' `Event::stop_propagation` has no effect in Freya,
+// so these are called through the event data, e.g `e.data.stop_propagation()`.
+macro_rules! impl_propagation {
+    ($($data:ty),*) => {
+        $(
+            impl $data {
+                /// Stop the event from reaching the next elements in the capture and bubble phases.
+                pub fn stop_propagation(&self) {
+                    self.propagation.stop();
+                }
+
+                /// Check if a previous listener stopped this event.
+                pub fn is_propagation_stopped(&self) -> bool {
+                    self.propagation.is_stopped()
+                }
+
+                /// Prevent the default action of this event, e.g moving the focus.
+                pub fn prevent_default(&self) {
+                    self.propagation.prevent_default();
+                }
+
+                /// Check if a previous listener prevented the default action of this event.
+                pub fn is_default_prevented(&self) -> bool {
+                    self.propagation.is_default_prevented()
+                }
+
+                /// Get the [`EventPropagation`] shared by all the listeners of this event.
+                pub fn get_propagation(&self) -> &EventPropagation {
+                    &self.propagation
+                }
+
+                #[doc(hidden)]
+                pub fn with_propagation(mut self, propagation: EventPropagation) -> Self {
+                    self.propagation = propagation;
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_propagation!(
+    CompositionData,
+    FileData,
+    FocusData,
+    KeyboardData,
+    MouseData,
+    PointerData,
+    TextInputData,
+    TouchData,
+    WheelData
+);
@@ -0,0 +1,24 @@
+use crate::events::EventPropagation;
+
+/// Data of a Text Input event, emitted when an input method commits some text.
+#[derive(Debug, Clone)]
+pub struct TextInputData {
+    text: String,
+    pub(crate) propagation: EventPropagation,
+}
+
+impl TextInputData {
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            propagation: EventPropagation::default(),
+        }
+    }
+}
+
+impl TextInputData {
+    /// Get the committed text.
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+}
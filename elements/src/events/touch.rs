@@ -1,3 +1,4 @@
+use crate::events::EventPropagation;
 use torin::geometry::CursorPoint;
 pub use winit::event::{Force, TouchPhase};
 
@@ -9,6 +10,7 @@ pub struct TouchData {
     pub finger_id: u64,
     pub phase: TouchPhase,
     pub force: Option<Force>,
+    pub(crate) propagation: EventPropagation,
 }
 
 impl TouchData {
@@ -25,6 +27,7 @@ impl TouchData {
             finger_id,
             phase,
             force,
+            propagation: EventPropagation::default(),
         }
     }
 
@@ -1,14 +1,21 @@
+use crate::events::EventPropagation;
+
 /// Data of a Wheel event.
 #[derive(Debug, Clone)]
 pub struct WheelData {
     #[allow(dead_code)]
     delta_x: f64,
     delta_y: f64,
+    pub(crate) propagation: EventPropagation,
 }
 
 impl WheelData {
     pub fn new(delta_x: f64, delta_y: f64) -> Self {
-        Self { delta_x, delta_y }
+        Self {
+            delta_x,
+            delta_y,
+            propagation: EventPropagation::default(),
+        }
     }
 }
 
@@ -0,0 +1,55 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+fn app(cx: Scope) -> Element {
+    let items = use_state(cx, || vec![0, 1, 2]);
+    let next_item = use_state(cx, || 3);
+
+    let insert = move |_| {
+        let item = *next_item.get();
+        next_item.set(item + 1);
+        items.with_mut(|items| items.insert(0, item));
+    };
+
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "15",
+            Button {
+                onclick: insert,
+                label {
+                    "Insert"
+                }
+            }
+            items.get().iter().map(|item| {
+                let item = *item;
+                rsx!(
+                    rect {
+                        key: "{item}",
+                        width: "200",
+                        height: "50",
+                        padding: "15",
+                        radius: "10",
+                        background: "rgb(150, 200, 225)",
+                        hover_background: "rgb(125, 175, 200)",
+                        // Siblings slide to their new position instead of jumping
+                        animate_layout: "250ms ease-out",
+                        onclick: move |_| items.with_mut(|items| items.retain(|i| *i != item)),
+                        label {
+                            "Item {item}, click to remove"
+                        }
+                    }
+                )
+            })
+        }
+    )
+}
@@ -0,0 +1,50 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+static RUST_LOGO: &[u8] = include_bytes!("./rust_logo.png");
+
+fn app(cx: Scope) -> Element {
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            direction: "horizontal",
+            ["normal", "multiply", "screen", "difference", "luminosity"].into_iter().map(|mode| {
+                rsx!(
+                    rect {
+                        key: "{mode}",
+                        width: "20%",
+                        height: "100%",
+                        padding: "5",
+                        label {
+                            height: "25",
+                            "{mode}"
+                        }
+                        rect {
+                            width: "100%",
+                            height: "calc(100% - 25)",
+                            radius: "15",
+                            background: "rgb(255, 165, 0)",
+                            image {
+                                image_data: bytes_to_data(cx, RUST_LOGO),
+                                width: "100%",
+                                height: "100%",
+                                image_fit: "contain",
+                                blend_mode: "{mode}",
+                            }
+                        }
+                    }
+                )
+            })
+        }
+    )
+}
@@ -0,0 +1,59 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+static RUST_LOGO: &[u8] = include_bytes!("./rust_logo.png");
+
+fn app(cx: Scope) -> Element {
+    let blur = use_state(cx, || 10);
+
+    let onwheel = move |e: WheelEvent| {
+        let y = e.get_delta_y() as i32;
+        blur.set((*blur.get() + y.signum()).max(0));
+    };
+
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            onwheel: onwheel,
+            image {
+                image_data: bytes_to_data(cx, RUST_LOGO),
+                width: "100%",
+                height: "300",
+                image_fit: "cover",
+            }
+            // Moved up so the panel is painted on top of the image
+            rect {
+                width: "100%",
+                height: "0",
+                padding: "0 50",
+                scroll_y: "-225",
+                rect {
+                    width: "100%",
+                    height: "150",
+                    radius: "20",
+                    padding: "20",
+                    background: "rgb(255, 255, 255, 60)",
+                    backdrop_blur: "{blur}",
+                    label {
+                        font_size: "22",
+                        "Frosted glass"
+                    }
+                    label {
+                        blur: "2",
+                        "Scroll to change the blur"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -139,6 +139,43 @@ fn app(cx: Scope) -> Element {
                     "Ultra Expanded\n"
                 }
             }
+            paragraph {
+                width: "20%",
+                line_height: "2",
+
+                text {
+                    font_size: "20",
+                    decoration: "underline",
+                    "Underline\n"
+                }
+                text {
+                    font_size: "20",
+                    decoration: "line-through",
+                    decoration_color: "red",
+                    "Line Through\n"
+                }
+                text {
+                    font_size: "20",
+                    decoration: "underline overline",
+                    decoration_style: "wavy",
+                    "Wavy\n"
+                }
+                text {
+                    font_size: "20",
+                    letter_spacing: "4",
+                    "Letter Spacing\n"
+                }
+                text {
+                    font_size: "20",
+                    word_spacing: "12",
+                    "Word Spacing\n"
+                }
+                text {
+                    font_size: "20",
+                    text_shadow: "2 2 3 rgb(0, 0, 0)",
+                    "Shadow\n"
+                }
+            }
         }
     )
 }
@@ -0,0 +1,46 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+static RUST_LOGO: &[u8] = include_bytes!("./rust_logo.png");
+
+fn app(cx: Scope) -> Element {
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            direction: "horizontal",
+            ["fill", "contain", "cover", "none", "scale-down"].into_iter().map(|fit| {
+                rsx!(
+                    rect {
+                        key: "{fit}",
+                        width: "20%",
+                        height: "100%",
+                        padding: "5",
+                        label {
+                            height: "25",
+                            "{fit}"
+                        }
+                        image {
+                            image_data: bytes_to_data(cx, RUST_LOGO),
+                            width: "100%",
+                            height: "calc(100% - 25)",
+                            radius: "15",
+                            image_fit: "{fit}",
+                            image_align: "top",
+                            sampling: "mitchell",
+                        }
+                    }
+                )
+            })
+        }
+    )
+}
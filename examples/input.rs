@@ -10,8 +10,6 @@ fn main() {
 }
 
 fn app(cx: Scope) -> Element {
-    use_init_focus(cx);
-
     let values = use_state(cx, || (String::new(), String::new()));
 
     render!(
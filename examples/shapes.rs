@@ -0,0 +1,67 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+fn app(cx: Scope) -> Element {
+    let liked = use_state(cx, || false);
+    let progress = use_state(cx, || 25.0);
+
+    let heart_color = if *liked.get() {
+        "rgb(220, 40, 60)"
+    } else {
+        "rgb(180, 180, 180)"
+    };
+
+    // The ring is drawn with a dash as long as the progress, followed by a gap
+    let ring_length = 2.0 * std::f32::consts::PI * 45.0;
+    let ring_dash = format!("{} {}", ring_length * *progress.get() / 100.0, ring_length);
+
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            label {
+                "Click the heart or the ring"
+            }
+            line {
+                width: "100%",
+                height: "20",
+                stroke: "rgb(200, 200, 200)",
+                stroke_dash: "6 4",
+            }
+            rect {
+                direction: "horizontal",
+                path {
+                    width: "100",
+                    height: "100",
+                    d: "M50 90 L12 52 A22 22 0 0 1 50 22 A22 22 0 0 1 88 52 Z",
+                    fill: "{heart_color}",
+                    onclick: move |_| liked.set(!liked.get()),
+                }
+                circle {
+                    width: "100",
+                    height: "100",
+                    stroke: "rgb(40, 120, 220)",
+                    stroke_width: "10",
+                    stroke_dash: "{ring_dash}",
+                    rotate: "-90",
+                    onclick: move |_| progress.set((*progress.get() + 25.0) % 125.0),
+                }
+                polygon {
+                    width: "100",
+                    height: "100",
+                    points: "20%,20% 80%,50% 20%,80%",
+                    fill: "rgb(60, 60, 60)",
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,45 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+fn app(cx: Scope) -> Element {
+    let expanded = use_state(cx, || false);
+
+    let (width, radius, rotate) = if *expanded.get() {
+        ("350", "50", "10")
+    } else {
+        ("150", "10", "0")
+    };
+
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            display: "center",
+            direction: "both",
+            rect {
+                width: "{width}",
+                height: "150",
+                radius: "{radius}",
+                rotate: "{rotate}",
+                background: "rgb(60, 60, 60)",
+                hover_background: "rgb(230, 90, 30)",
+                transition: "background 200ms ease-out, width 400ms ease-in-out, radius 400ms, rotate 400ms ease-in-out",
+                display: "center",
+                direction: "both",
+                color: "white",
+                onclick: move |_| expanded.set(!expanded.get()),
+                label {
+                    "Click me"
+                }
+            }
+        }
+    )
+}
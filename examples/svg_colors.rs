@@ -0,0 +1,54 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+static FERRIS: &[u8] = include_bytes!("./ferris.svg");
+
+fn app(cx: Scope) -> Element {
+    let hovered = use_state(cx, || false);
+    let ferris = bytes_to_data(cx, FERRIS);
+
+    let color = if *hovered.get() {
+        "rgb(230, 90, 30)"
+    } else {
+        "rgb(60, 60, 60)"
+    };
+
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            display: "center",
+            direction: "both",
+            rect {
+                width: "300",
+                height: "250",
+                padding: "15",
+                radius: "15",
+                background: "rgb(235, 235, 235)",
+                color: "{color}",
+                onmouseover: move |_| hovered.set(true),
+                onmouseleave: move |_| hovered.set(false),
+                // The icon follows the text color of its parent
+                svg {
+                    width: "100%",
+                    height: "calc(100% - 25)",
+                    svg_data: ferris,
+                    fill: "currentColor",
+                }
+                label {
+                    height: "25",
+                    align: "center",
+                    "Hover me"
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,64 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+#[allow(non_snake_case)]
+#[inline_props]
+fn Item(cx: Scope, name: &'static str) -> Element {
+    let focus = use_node_focus(cx);
+    let last_key = use_state(cx, String::new);
+
+    let background = if focus.is_focused() {
+        "rgb(200, 225, 255)"
+    } else {
+        "rgb(235, 235, 235)"
+    };
+
+    render!(
+        rect {
+            width: "100%",
+            height: "50",
+            padding: "15",
+            background: "{background}",
+            focus_reference: focus.attribute(cx),
+            onkeydown: move |e: KeyboardEvent| last_key.set(e.data.key.to_string()),
+            label {
+                "{name}, last key: {last_key}"
+            }
+        }
+    )
+}
+
+fn app(cx: Scope) -> Element {
+    render!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "15",
+            label {
+                "Press Tab and Shift+Tab to move the focus"
+            }
+            Item { name: "First" }
+            Item { name: "Second" }
+            // Negative indexes are skipped by Tab, but can still be focused by clicking them
+            rect {
+                width: "100%",
+                height: "50",
+                padding: "15",
+                tab_index: "-1",
+                focus_border: "2 rgb(100, 150, 255)",
+                label {
+                    "Only focusable with the mouse"
+                }
+            }
+            Item { name: "Third" }
+        }
+    )
+}
@@ -1,5 +1,6 @@
 use dioxus_core::Component;
 use freya_renderer::run_app;
+use freya_renderer::SharedAssetsCacheStats;
 use freya_renderer::WindowConfig;
 
 #[cfg(not(doctest))]
@@ -163,6 +164,7 @@ pub fn launch_cfg<T: 'static + Clone + Send>(root: Component, win_config: Window
 
     let fdom = FreyaDOM::default();
     let sdom = SafeDOM::new(fdom);
+    let assets_cache_stats = SharedAssetsCacheStats::default();
 
     let (vdom, mutations_notifier, hovered_node) = {
         #[cfg(feature = "devtools")]
@@ -179,6 +181,7 @@ pub fn launch_cfg<T: 'static + Clone + Send>(root: Component, win_config: Window
                 root,
                 mutations_notifier.clone(),
                 hovered_node.clone(),
+                assets_cache_stats.clone(),
             );
             (vdom, Some(mutations_notifier), hovered_node)
         }
@@ -190,5 +193,12 @@ pub fn launch_cfg<T: 'static + Clone + Send>(root: Component, win_config: Window
             (vdom, None, None)
         }
     };
-    run_app(vdom, sdom, win_config, mutations_notifier, hovered_node);
+    run_app(
+        vdom,
+        sdom,
+        win_config,
+        mutations_notifier,
+        hovered_node,
+        assets_cache_stats,
+    );
 }
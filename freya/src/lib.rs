@@ -81,7 +81,7 @@ pub mod prelude {
     pub use freya_elements::events::*;
     pub use freya_elements::*;
     pub use freya_hooks::*;
-    pub use freya_node_state::{bytes_to_data, CustomAttributeValues};
+    pub use freya_node_state::{bytes_to_data, CustomAttributeValues, Stylesheet};
     pub use freya_renderer::WindowConfig;
     pub use torin::prelude::*;
 
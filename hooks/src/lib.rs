@@ -11,8 +11,11 @@ mod use_animation_transition;
 mod use_canvas;
 mod use_editable;
 mod use_focus;
+mod use_image_playback;
 mod use_node;
 mod use_platform;
+mod use_shortcut;
+mod use_stylesheet;
 mod use_theme;
 
 #[cfg(feature = "use_camera")]
@@ -28,8 +31,11 @@ pub use use_animation_transition::*;
 pub use use_canvas::*;
 pub use use_editable::*;
 pub use use_focus::*;
+pub use use_image_playback::*;
 pub use use_node::*;
 pub use use_platform::*;
+pub use use_shortcut::*;
+pub use use_stylesheet::*;
 pub use use_theme::*;
 
 #[cfg(feature = "use_camera")]
@@ -14,6 +14,9 @@ pub struct RopeEditor {
 
     /// Selected text range
     selected: Option<(usize, usize)>,
+
+    /// Text being composed by an input method, displayed at the cursor until it's committed
+    preedit: Option<String>,
 }
 
 impl Display for RopeEditor {
@@ -30,8 +33,35 @@ impl RopeEditor {
             cursor,
             selected: None,
             mode,
+            preedit: None,
         }
     }
+
+    /// Set the text an input method is composing, an empty text removes it.
+    pub fn set_preedit(&mut self, text: &str) {
+        self.preedit = if text.is_empty() {
+            None
+        } else {
+            Some(text.to_string())
+        };
+    }
+
+    /// Get the text an input method is composing, if any.
+    pub fn preedit(&self) -> Option<&str> {
+        self.preedit.as_deref()
+    }
+
+    /// Split the text at the cursor, where the preedit text is displayed.
+    /// Editors render the preedit text between the two halves, usually underlined.
+    pub fn preedit_spans(&self) -> Option<(String, &str, String)> {
+        let preedit = self.preedit.as_deref()?;
+        let cursor_pos = self.cursor_pos();
+        Some((
+            self.rope.slice(..cursor_pos).to_string(),
+            preedit,
+            self.rope.slice(cursor_pos..).to_string(),
+        ))
+    }
 }
 
 impl TextEditor for RopeEditor {
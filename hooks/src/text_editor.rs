@@ -168,6 +168,20 @@ pub trait TextEditor: Sized + Clone + Display {
         self.cursor_mut().move_to(row, col)
     }
 
+    /// Insert some text at the cursor and move the cursor after it
+    fn insert_text(&mut self, text: &str) -> TextEvent {
+        if text.is_empty() {
+            return TextEvent::None;
+        }
+
+        let char_idx = self.cursor_pos();
+        self.insert(text, char_idx);
+        self.set_cursor_pos(char_idx + text.chars().count());
+        self.unhighlight();
+
+        TextEvent::TextChanged
+    }
+
     // Return the highlighted text from a given editor Id
     fn highlights(&self, editor_id: usize) -> Option<(usize, usize)>;
 
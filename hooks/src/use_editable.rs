@@ -6,7 +6,7 @@ use std::{
 use dioxus_core::{AttributeValue, Scope, ScopeState};
 use dioxus_hooks::{use_effect, use_ref, use_state, UseRef, UseState};
 use freya_common::{CursorLayoutResponse, EventMessage};
-use freya_elements::events::{KeyboardData, MouseData};
+use freya_elements::events::{CompositionData, KeyboardData, MouseData, TextInputData};
 use freya_node_state::{CursorReference, CustomAttributeValues};
 pub use ropey::Rope;
 use tokio::sync::{mpsc::unbounded_channel, mpsc::UnboundedSender};
@@ -21,6 +21,12 @@ pub enum EditableEvent {
     MouseOver(Rc<MouseData>, usize),
     MouseDown(Rc<MouseData>, usize),
     KeyDown(Rc<KeyboardData>),
+    /// An input method started or updated its composition.
+    Composition(Rc<CompositionData>),
+    /// An input method finished its composition.
+    CompositionEnd,
+    /// An input method committed some text.
+    TextInput(Rc<TextInputData>),
 }
 
 /// How the editable content must behave.
@@ -110,6 +116,25 @@ impl UseEditable {
                 self.editor.with_mut(|editor| {
                     let event = editor.process_key(&e.key, &e.code, &e.modifiers);
 
+                    if event == TextEvent::TextChanged {
+                        *self.selecting_text_with_mouse.write_silent() = None;
+                    }
+                });
+            }
+            EditableEvent::Composition(e) => {
+                self.editor.with_mut(|editor| {
+                    editor.set_preedit(e.get_data());
+                });
+            }
+            EditableEvent::CompositionEnd => {
+                self.editor.with_mut(|editor| {
+                    editor.set_preedit("");
+                });
+            }
+            EditableEvent::TextInput(e) => {
+                self.editor.with_mut(|editor| {
+                    let event = editor.insert_text(e.get_text());
+
                     if event == TextEvent::TextChanged {
                         *self.selecting_text_with_mouse.write_silent() = None;
                     }
@@ -1,7 +1,14 @@
-use dioxus_core::ScopeState;
-use dioxus_hooks::{use_shared_state, use_shared_state_provider, UseSharedState};
+use dioxus_core::{AttributeValue, Scope, ScopeState};
+use dioxus_hooks::{
+    use_effect, use_shared_state, use_shared_state_provider, use_state, UseSharedState,
+};
+use freya_common::EventMessage;
+use freya_node_state::{CustomAttributeValues, FocusReference};
+use tokio::sync::mpsc::unbounded_channel;
 use uuid::Uuid;
 
+use crate::{use_platform, UsePlatform};
+
 /// Subscribe and change the current focus.
 pub fn use_focus(cx: &ScopeState) -> (bool, impl Fn() + '_) {
     let my_id = cx.use_hook(Uuid::new_v4);
@@ -31,10 +38,98 @@ pub fn use_init_focus(cx: &ScopeState) {
     use_shared_state_provider(cx, Uuid::new_v4);
 }
 
+/// Keyboard focus of the element bound with [`UseNodeFocus::attribute`].
+#[derive(Clone)]
+pub struct UseNodeFocus {
+    focus_reference: FocusReference,
+    focused: bool,
+    platform: UsePlatform,
+}
+
+impl UseNodeFocus {
+    /// Attribute value to pass to the `focus_reference` attribute of an element.
+    pub fn attribute<'a, T>(&self, cx: Scope<'a, T>) -> AttributeValue<'a> {
+        cx.any_value(CustomAttributeValues::FocusReference(
+            self.focus_reference.clone(),
+        ))
+    }
+
+    /// Check if the element has the keyboard focus.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Move the keyboard focus to the element.
+    pub fn focus(&self) {
+        self.platform
+            .send(EventMessage::FocusNode(self.focus_reference.id))
+            .ok();
+    }
+}
+
+/// Bind the keyboard focus to an element, which becomes focusable.
+/// The element is focused when it's pressed, reached with `Tab` or with [`UseNodeFocus::focus`].
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app(cx: Scope) -> Element {
+///     let focus = use_node_focus(cx);
+///
+///     let background = if focus.is_focused() { "rgb(200, 200, 200)" } else { "white" };
+///
+///     render!(
+///         rect {
+///             focus_reference: focus.attribute(cx),
+///             background: "{background}",
+///             onkeydown: move |e: KeyboardEvent| println!("{:?}", e.data.key),
+///             label {
+///                 "Press Tab to focus me"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_node_focus(cx: &ScopeState) -> UseNodeFocus {
+    let focused = use_state(cx, || false);
+    let platform = use_platform(cx);
+
+    let channel = cx.use_hook(|| {
+        let (tx, rx) = unbounded_channel::<bool>();
+        (Uuid::new_v4(), tx, Some(rx))
+    });
+
+    let focus_reference = FocusReference {
+        id: channel.0,
+        sender: channel.1.clone(),
+    };
+
+    use_effect(cx, (), move |_| {
+        let rx = channel.2.take();
+        let focused = focused.clone();
+        cx.spawn(async move {
+            let mut rx = rx.unwrap();
+            while let Some(is_focused) = rx.recv().await {
+                if *focused.current() != is_focused {
+                    focused.set(is_focused);
+                }
+            }
+        });
+        async move {}
+    });
+
+    UseNodeFocus {
+        focus_reference,
+        focused: *focused.get(),
+        platform,
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{use_focus, use_init_focus};
+    use crate::{use_focus, use_init_focus, use_node_focus};
     use freya::prelude::*;
+    use freya_elements::events::keyboard::{Code, Key, Modifiers};
     use freya_testing::{launch_test_with_config, FreyaEvent, MouseButton, TestingConfig};
 
     #[tokio::test]
@@ -101,4 +196,85 @@ mod test {
         assert_eq!(root.get(0).get(0).text(), Some("false"));
         assert_eq!(root.get(1).get(0).text(), Some("true"));
     }
+
+    #[tokio::test]
+    pub async fn track_node_focus() {
+        #[allow(non_snake_case)]
+        fn Child(cx: Scope) -> Element {
+            let focus = use_node_focus(cx);
+
+            render!(
+                rect {
+                    width: "100%",
+                    height: "33%",
+                    focus_reference: focus.attribute(cx),
+                    "{focus.is_focused()}"
+                }
+            )
+        }
+
+        fn use_node_focus_app(cx: Scope) -> Element {
+            let focus = use_node_focus(cx);
+
+            render!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    Child {},
+                    Child {},
+                    rect {
+                        width: "100%",
+                        height: "33%",
+                        focus_reference: focus.attribute(cx),
+                        onclick: move |_| focus.focus(),
+                        "{focus.is_focused()}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            use_node_focus_app,
+            TestingConfig::default().with_size((100.0, 100.0).into()),
+        );
+
+        utils.wait_for_update().await;
+        let root = utils.root().get(0);
+        assert_eq!(root.get(0).get(0).text(), Some("false"));
+        assert_eq!(root.get(1).get(0).text(), Some("false"));
+
+        // Pressing the first rect focuses it
+        utils.push_event(FreyaEvent::Mouse {
+            name: "mousedown".to_string(),
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).text(), Some("true"));
+        assert_eq!(root.get(1).get(0).text(), Some("false"));
+
+        // Tab moves the focus to the second rect
+        utils.push_event(FreyaEvent::Keyboard {
+            name: "keydown".to_string(),
+            key: Key::Tab,
+            code: Code::Tab,
+            modifiers: Modifiers::empty(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).text(), Some("false"));
+        assert_eq!(root.get(1).get(0).text(), Some("true"));
+
+        // The last rect focuses itself when clicked
+        utils.push_event(FreyaEvent::Mouse {
+            name: "click".to_string(),
+            cursor: (5.0, 80.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(root.get(1).get(0).text(), Some("false"));
+        assert_eq!(root.get(2).get(0).text(), Some("true"));
+    }
 }
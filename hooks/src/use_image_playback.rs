@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use dioxus_core::{AttributeValue, Scope, ScopeState};
+use freya_common::EventMessage;
+use freya_node_state::{CustomAttributeValues, ImagePlayback, PlaybackCommand};
+
+use crate::{use_platform, UsePlatform};
+
+/// Controls the playback of an animated `image`.
+#[derive(Clone)]
+pub struct UseImagePlayback {
+    playback: ImagePlayback,
+    platform: UsePlatform,
+}
+
+impl UseImagePlayback {
+    /// Attribute value to pass to the `image_playback` attribute of an `image`.
+    pub fn attribute<'a, T>(&self, cx: Scope<'a, T>) -> AttributeValue<'a> {
+        cx.any_value(CustomAttributeValues::ImagePlayback(self.playback.clone()))
+    }
+
+    /// Resume the playback.
+    pub fn play(&self) {
+        self.send(PlaybackCommand::Play);
+    }
+
+    /// Pause the playback.
+    pub fn pause(&self) {
+        self.send(PlaybackCommand::Pause);
+    }
+
+    /// Move the playback to the given position.
+    pub fn seek(&self, position: Duration) {
+        self.send(PlaybackCommand::Seek(position));
+    }
+
+    /// Check if the image is playing, as of the last rendered frame.
+    pub fn is_playing(&self) -> bool {
+        self.playback.0.lock().unwrap().playing
+    }
+
+    /// Position of the playback, as of the last rendered frame.
+    pub fn position(&self) -> Duration {
+        self.playback.0.lock().unwrap().position
+    }
+
+    /// Total duration of the animation.
+    pub fn duration(&self) -> Duration {
+        self.playback.0.lock().unwrap().duration
+    }
+
+    fn send(&self, command: PlaybackCommand) {
+        self.playback.0.lock().unwrap().commands.push(command);
+        self.platform.send(EventMessage::RequestRerender).ok();
+    }
+}
+
+/// Control the playback of animated images (GIF, APNG or WebP).
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// static LOADER: &[u8] = &[];
+///
+/// fn app(cx: Scope) -> Element {
+///     let playback = use_image_playback(cx);
+///
+///     render!(
+///         image {
+///             image_data: bytes_to_data(cx, LOADER),
+///             image_playback: playback.attribute(cx),
+///             autoplay: "false",
+///             onclick: move |_| {
+///                 if playback.is_playing() {
+///                     playback.pause()
+///                 } else {
+///                     playback.play()
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_image_playback(cx: &ScopeState) -> &UseImagePlayback {
+    let platform = use_platform(cx);
+    cx.use_hook(|| UseImagePlayback {
+        playback: ImagePlayback::default(),
+        platform,
+    })
+}
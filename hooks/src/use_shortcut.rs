@@ -0,0 +1,784 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use dioxus_core::ScopeState;
+use freya_elements::events::keyboard::{Code, Key, Modifiers};
+use freya_elements::events::{EventPropagation, KeyboardData};
+use tracing::warn;
+use uuid::Uuid;
+
+/// Max time between the keys of a sequence like `g g`.
+pub const SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Combination of modifiers and a key, like `Ctrl+Shift+P`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyChord {
+    pub modifiers: Modifiers,
+    pub key: Key,
+    /// Physical key of letters, digits and `Space`, so they match no matter what character they produce,
+    /// e.g `Ctrl+P` usually produces a control character.
+    pub code: Option<Code>,
+}
+
+impl KeyChord {
+    /// Parse a chord like `Ctrl+Shift+P`, `Alt+Enter` or `?`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut modifiers = Modifiers::empty();
+        let mut parts = value.split('+').peekable();
+
+        while let Some(part) = parts.next() {
+            // The last part is the key
+            if parts.peek().is_none() {
+                let (key, code) = parse_key(part)?;
+                return Some(Self {
+                    modifiers,
+                    key,
+                    code,
+                });
+            }
+
+            let modifier = match part.to_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CONTROL,
+                "shift" => Modifiers::SHIFT,
+                "alt" | "option" => Modifiers::ALT,
+                "meta" | "super" | "cmd" | "command" => Modifiers::META,
+                _ => return None,
+            };
+            modifiers.insert(modifier);
+        }
+
+        None
+    }
+
+    /// Check if a pressed key matches this chord.
+    pub fn matches(&self, key: &Key, code: &Code, modifiers: Modifiers) -> bool {
+        let same_key = match (&self.key, key) {
+            (Key::Character(expected), Key::Character(pressed)) => {
+                expected.to_lowercase() == pressed.to_lowercase()
+            }
+            (expected, pressed) => expected == pressed,
+        };
+        let same_code = self.code.as_ref() == Some(code);
+
+        if !same_key && !same_code {
+            return false;
+        }
+
+        // Symbols like `?` need Shift in most layouts, so it only counts for the other keys
+        if self.code.is_none() && matches!(self.key, Key::Character(_)) {
+            let mut modifiers = modifiers;
+            let mut expected_modifiers = self.modifiers;
+            modifiers.remove(Modifiers::SHIFT);
+            expected_modifiers.remove(Modifiers::SHIFT);
+            modifiers == expected_modifiers
+        } else {
+            modifiers == self.modifiers
+        }
+    }
+}
+
+/// Parse a key name like `P`, `5`, `Space` or `Enter`.
+fn parse_key(value: &str) -> Option<(Key, Option<Code>)> {
+    if value.eq_ignore_ascii_case("space") {
+        return Some((Key::Character(" ".to_string()), Some(Code::Space)));
+    }
+
+    let mut chars = value.chars();
+    if let (Some(char), None) = (chars.next(), chars.next()) {
+        let code = if char.is_ascii_alphabetic() {
+            Code::from_str(&format!("Key{}", char.to_ascii_uppercase())).ok()
+        } else if char.is_ascii_digit() {
+            Code::from_str(&format!("Digit{char}")).ok()
+        } else {
+            None
+        };
+        return Some((Key::Character(char.to_lowercase().to_string()), code));
+    }
+
+    match Key::from_str(value) {
+        Ok(Key::Character(_)) | Err(_) => None,
+        Ok(key) => Some((key, None)),
+    }
+}
+
+/// Sequence of chords, like `Ctrl+K Ctrl+S` or `g g`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shortcut {
+    pub chords: Vec<KeyChord>,
+    text: String,
+}
+
+impl Shortcut {
+    /// Parse a shortcut made of chords separated by spaces.
+    pub fn parse(value: &str) -> Option<Self> {
+        let chords = value
+            .split_whitespace()
+            .map(KeyChord::parse)
+            .collect::<Option<Vec<KeyChord>>>()?;
+
+        if chords.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            chords,
+            text: value.to_string(),
+        })
+    }
+
+    /// Check if the pressed keys match the first chords of this shortcut.
+    fn starts_with(&self, pressed_keys: &[PressedKey]) -> bool {
+        self.chords.len() >= pressed_keys.len()
+            && self
+                .chords
+                .iter()
+                .zip(pressed_keys)
+                .all(|(chord, (key, code, modifiers))| chord.matches(key, code, *modifiers))
+    }
+
+    /// Check if the chords of this shortcut are also the first chords of another shortcut.
+    fn is_prefix_of(&self, other: &Self) -> bool {
+        self.chords.len() <= other.chords.len()
+            && self.chords.iter().zip(&other.chords).all(|(a, b)| a == b)
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Where a shortcut is active.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShortcutScope {
+    /// Active anywhere in the app.
+    Global,
+    /// Active while an element in the subtree of a [`UseShortcutScope`] has the keyboard focus.
+    /// It takes precedence over the global shortcuts and the ones of the outer scopes.
+    Focused(Uuid),
+}
+
+/// Two shortcuts of the same scope where one of them can never be triggered,
+/// because they are the same or the first one is the beginning of the second one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortcutConflict {
+    pub scope: ShortcutScope,
+    pub first: Shortcut,
+    pub second: Shortcut,
+}
+
+impl Display for ShortcutConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Shortcut `{}` prevents `{}` from being triggered in the {:?} scope",
+            self.first, self.second, self.scope
+        )
+    }
+}
+
+type PressedKey = (Key, Code, Modifiers);
+
+struct RegisteredShortcut {
+    id: Uuid,
+    shortcut: Shortcut,
+    scope: ShortcutScope,
+    handler: Rc<dyn Fn()>,
+}
+
+impl RegisteredShortcut {
+    /// Check if this shortcut and another one conflict.
+    fn conflict(&self, other: &Self) -> Option<ShortcutConflict> {
+        if self.scope != other.scope {
+            return None;
+        }
+
+        let (first, second) = if self.shortcut.is_prefix_of(&other.shortcut) {
+            (self, other)
+        } else if other.shortcut.is_prefix_of(&self.shortcut) {
+            (other, self)
+        } else {
+            return None;
+        };
+
+        Some(ShortcutConflict {
+            scope: first.scope,
+            first: first.shortcut.clone(),
+            second: second.shortcut.clone(),
+        })
+    }
+}
+
+#[derive(Default)]
+struct Shortcuts {
+    registered: Vec<RegisteredShortcut>,
+    /// Keys pressed so far of a sequence.
+    pending_keys: Vec<PressedKey>,
+    last_key: Option<Instant>,
+    /// Scopes the keyboard events went through while bubbling, from the innermost one.
+    entered_scopes: Vec<(Uuid, EventPropagation)>,
+}
+
+/// Registry of the shortcuts of an app, created with [`use_init_shortcut_provider`].
+#[derive(Clone, Default)]
+pub struct ShortcutProvider(Rc<RefCell<Shortcuts>>);
+
+impl ShortcutProvider {
+    /// Register a shortcut, or update it if it was already registered with the same id.
+    /// New conflicts with other shortcuts are reported as warnings.
+    pub fn register(
+        &self,
+        id: Uuid,
+        shortcut: Shortcut,
+        scope: ShortcutScope,
+        handler: impl Fn() + 'static,
+    ) {
+        let registered_shortcut = RegisteredShortcut {
+            id,
+            shortcut,
+            scope,
+            handler: Rc::new(handler),
+        };
+
+        let mut shortcuts = self.0.borrow_mut();
+
+        let existing = shortcuts.registered.iter().position(|s| s.id == id);
+        let changed = existing
+            .map(|i| {
+                let existing = &shortcuts.registered[i];
+                existing.shortcut != registered_shortcut.shortcut
+                    || existing.scope != registered_shortcut.scope
+            })
+            .unwrap_or(true);
+
+        // The handler is updated in every render, only report the conflicts once
+        if changed {
+            for other in &shortcuts.registered {
+                if other.id == id {
+                    continue;
+                }
+                if let Some(conflict) = registered_shortcut.conflict(other) {
+                    warn!("{conflict}");
+                }
+            }
+        }
+
+        if let Some(i) = existing {
+            shortcuts.registered[i] = registered_shortcut;
+        } else {
+            shortcuts.registered.push(registered_shortcut);
+        }
+    }
+
+    /// Remove a shortcut.
+    pub fn unregister(&self, id: Uuid) {
+        self.0.borrow_mut().registered.retain(|s| s.id != id);
+    }
+
+    /// Get the registered shortcuts that conflict with others.
+    pub fn conflicts(&self) -> Vec<ShortcutConflict> {
+        let shortcuts = self.0.borrow();
+        let mut conflicts = Vec::new();
+
+        for (i, first) in shortcuts.registered.iter().enumerate() {
+            for second in &shortcuts.registered[i + 1..] {
+                conflicts.extend(first.conflict(second));
+            }
+        }
+
+        conflicts
+    }
+
+    /// Mark that a keyboard event went through a scope. See [`UseShortcutScope::enter`].
+    fn enter_scope(&self, scope_id: Uuid, data: &KeyboardData) {
+        self.0
+            .borrow_mut()
+            .entered_scopes
+            .push((scope_id, data.get_propagation().clone()));
+    }
+
+    /// Trigger the shortcut completed by a keyboard event, must be called from the `onkeydown` of the root element.
+    /// Returns `true` if a shortcut was triggered, in which case the default action of the event is prevented.
+    pub fn process_key(&self, data: &KeyboardData) -> bool {
+        let mut shortcuts = self.0.borrow_mut();
+
+        // Only the scopes this same event went through are active
+        let propagation = data.get_propagation();
+        let entered_scopes = std::mem::take(&mut shortcuts.entered_scopes)
+            .into_iter()
+            .filter(|(_, scope_propagation)| scope_propagation.is_same_event(propagation))
+            .map(|(scope_id, _)| scope_id)
+            .collect::<Vec<Uuid>>();
+
+        if matches!(
+            data.key,
+            Key::Shift | Key::Control | Key::Alt | Key::AltGraph | Key::Meta | Key::Super
+        ) {
+            return false;
+        }
+
+        let now = Instant::now();
+        let timed_out = shortcuts
+            .last_key
+            .map(|last_key| now.duration_since(last_key) > SEQUENCE_TIMEOUT)
+            .unwrap_or_default();
+        if timed_out {
+            shortcuts.pending_keys.clear();
+        }
+        shortcuts.last_key = Some(now);
+        shortcuts
+            .pending_keys
+            .push((data.key.clone(), data.code, data.modifiers));
+
+        // Scopes closer to the focused element go first
+        let priority = |scope: &ShortcutScope| match scope {
+            ShortcutScope::Global => Some(usize::MAX),
+            ShortcutScope::Focused(scope_id) => entered_scopes.iter().position(|id| id == scope_id),
+        };
+
+        loop {
+            let pending_keys = &shortcuts.pending_keys;
+            let candidates = shortcuts
+                .registered
+                .iter()
+                .filter_map(|s| Some((priority(&s.scope)?, s)))
+                .filter(|(_, s)| s.shortcut.starts_with(pending_keys));
+
+            let mut is_prefix = false;
+            let mut completed: Option<(usize, &RegisteredShortcut)> = None;
+            for (priority, registered_shortcut) in candidates {
+                if registered_shortcut.shortcut.chords.len() == pending_keys.len() {
+                    if completed.map(|(p, _)| priority < p).unwrap_or(true) {
+                        completed = Some((priority, registered_shortcut));
+                    }
+                } else {
+                    is_prefix = true;
+                }
+            }
+
+            if let Some((_, registered_shortcut)) = completed {
+                let handler = registered_shortcut.handler.clone();
+                shortcuts.pending_keys.clear();
+                drop(shortcuts);
+
+                data.prevent_default();
+                handler();
+                return true;
+            }
+
+            if is_prefix {
+                // Wait for the next keys of the sequence
+                return false;
+            }
+
+            // Start over from the last key, which might begin another sequence
+            if shortcuts.pending_keys.len() > 1 {
+                let last_key = shortcuts.pending_keys.pop();
+                shortcuts.pending_keys = last_key.into_iter().collect();
+            } else {
+                shortcuts.pending_keys.clear();
+                return false;
+            }
+        }
+    }
+}
+
+/// Create the [`ShortcutProvider`] where the shortcuts of the app are registered.
+///
+/// Keyboard events must be passed to it from the root element with [`ShortcutProvider::process_key`],
+/// as they bubble there from the focused element.
+pub fn use_init_shortcut_provider(cx: &ScopeState) -> ShortcutProvider {
+    cx.use_hook(|| cx.provide_context(ShortcutProvider::default()))
+        .clone()
+}
+
+/// Get the [`ShortcutProvider`] created with [`use_init_shortcut_provider`].
+pub fn use_shortcut_provider(cx: &ScopeState) -> Option<ShortcutProvider> {
+    cx.consume_context::<ShortcutProvider>()
+}
+
+/// Removes the shortcut when the component is dropped.
+struct ShortcutRegistration {
+    id: Uuid,
+    provider: ShortcutProvider,
+    /// Last invalid shortcut, so it's only reported once.
+    invalid_shortcut: Option<String>,
+}
+
+impl Drop for ShortcutRegistration {
+    fn drop(&mut self) {
+        self.provider.unregister(self.id);
+    }
+}
+
+/// Call a handler when a shortcut is pressed, like `Ctrl+Shift+P` or the sequence `g g`.
+///
+/// Shortcuts are chords separated by spaces, made of the modifiers `Ctrl`, `Shift`, `Alt` and `Meta`
+/// and a key like `P`, `5`, `?`, `Space` or any named [`Key`] like `Enter` or `ArrowUp`.
+/// Invalid shortcuts are ignored and logged as warnings.
+/// Conflicts with other shortcuts are logged too, and can be listed with [`ShortcutProvider::conflicts`].
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app(cx: Scope) -> Element {
+///     let shortcuts = use_init_shortcut_provider(cx);
+///     let count = use_state(cx, || 0);
+///
+///     use_shortcut(cx, "Ctrl+Shift+P", ShortcutScope::Global, {
+///         to_owned![count];
+///         move || count.modify(|count| count + 1)
+///     });
+///
+///     render!(
+///         rect {
+///             width: "100%",
+///             height: "100%",
+///             onkeydown: move |e: KeyboardEvent| {
+///                 shortcuts.process_key(&e.data);
+///             },
+///             label {
+///                 "Pressed {count} times"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_shortcut(
+    cx: &ScopeState,
+    shortcut: &str,
+    scope: ShortcutScope,
+    handler: impl Fn() + 'static,
+) {
+    let registration = cx.use_hook(|| ShortcutRegistration {
+        id: Uuid::new_v4(),
+        provider: use_shortcut_provider(cx)
+            .expect("`use_shortcut` must be used inside a `ShortcutProvider`"),
+        invalid_shortcut: None,
+    });
+
+    if let Some(parsed_shortcut) = Shortcut::parse(shortcut) {
+        registration.invalid_shortcut = None;
+        // The handler is updated in every render so it sees the latest values
+        registration
+            .provider
+            .register(registration.id, parsed_shortcut, scope, handler);
+    } else {
+        if registration.invalid_shortcut.as_deref() != Some(shortcut) {
+            warn!("Invalid shortcut `{shortcut}`");
+            registration.invalid_shortcut = Some(shortcut.to_string());
+        }
+        registration.provider.unregister(registration.id);
+    }
+}
+
+/// Scope of shortcuts that are only active while an element of a subtree has the keyboard focus.
+#[derive(Clone)]
+pub struct UseShortcutScope {
+    id: Uuid,
+    provider: ShortcutProvider,
+}
+
+impl UseShortcutScope {
+    /// Get the [`ShortcutScope`] to register shortcuts in.
+    pub fn scope(&self) -> ShortcutScope {
+        ShortcutScope::Focused(self.id)
+    }
+
+    /// Must be called from the `onkeydown` of the root element of the subtree.
+    pub fn enter(&self, data: &KeyboardData) {
+        self.provider.enter_scope(self.id, data);
+    }
+}
+
+/// Create a [`UseShortcutScope`] for the shortcuts of a subtree.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// #[allow(non_snake_case)]
+/// fn Editor(cx: Scope) -> Element {
+///     let shortcut_scope = use_shortcut_scope(cx);
+///     let focus = use_node_focus(cx);
+///
+///     use_shortcut(cx, "g g", shortcut_scope.scope(), || println!("Go to the top"));
+///
+///     render!(
+///         rect {
+///             focus_reference: focus.attribute(cx),
+///             onkeydown: move |e: KeyboardEvent| shortcut_scope.enter(&e.data),
+///             label {
+///                 "Focus me and press g twice"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_shortcut_scope(cx: &ScopeState) -> UseShortcutScope {
+    cx.use_hook(|| UseShortcutScope {
+        id: Uuid::new_v4(),
+        provider: use_shortcut_provider(cx)
+            .expect("`use_shortcut_scope` must be used inside a `ShortcutProvider`"),
+    })
+    .clone()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        use_init_shortcut_provider, use_node_focus, use_shortcut, use_shortcut_scope, KeyChord,
+        Shortcut, ShortcutProvider, ShortcutScope,
+    };
+    use freya::prelude::*;
+    use freya_elements::events::keyboard::{Code, Key, Modifiers};
+    use freya_testing::{launch_test_with_config, FreyaEvent, MouseButton, TestingConfig};
+    use uuid::Uuid;
+
+    fn key_down(key: Key, code: Code, modifiers: Modifiers) -> FreyaEvent {
+        FreyaEvent::Keyboard {
+            name: "keydown".to_string(),
+            key,
+            code,
+            modifiers,
+        }
+    }
+
+    #[test]
+    pub fn parse_shortcuts() {
+        assert_eq!(
+            KeyChord::parse("Ctrl+Shift+P"),
+            Some(KeyChord {
+                modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+                key: Key::Character("p".to_string()),
+                code: Some(Code::KeyP),
+            })
+        );
+        assert_eq!(
+            KeyChord::parse("Alt+Enter"),
+            Some(KeyChord {
+                modifiers: Modifiers::ALT,
+                key: Key::Enter,
+                code: None,
+            })
+        );
+        assert_eq!(
+            KeyChord::parse("Cmd+5").map(|chord| chord.code),
+            Some(Some(Code::Digit5))
+        );
+        assert_eq!(Shortcut::parse("g g").map(|s| s.chords.len()), Some(2));
+
+        assert_eq!(KeyChord::parse("Ctrl+"), None);
+        assert_eq!(KeyChord::parse("Hyper+P"), None);
+        assert_eq!(KeyChord::parse("Ctrl+Unknown"), None);
+        assert_eq!(Shortcut::parse(" "), None);
+    }
+
+    #[test]
+    pub fn match_chords() {
+        let chord = KeyChord::parse("Ctrl+P").unwrap();
+        // Ctrl+P usually produces a control character
+        assert!(chord.matches(
+            &Key::Character("\u{10}".to_string()),
+            &Code::KeyP,
+            Modifiers::CONTROL
+        ));
+        assert!(!chord.matches(
+            &Key::Character("p".to_string()),
+            &Code::KeyP,
+            Modifiers::CONTROL | Modifiers::SHIFT
+        ));
+
+        // Shift doesn't matter for symbols
+        let chord = KeyChord::parse("?").unwrap();
+        assert!(chord.matches(
+            &Key::Character("?".to_string()),
+            &Code::Slash,
+            Modifiers::SHIFT
+        ));
+    }
+
+    #[test]
+    pub fn detect_conflicts() {
+        let provider = ShortcutProvider::default();
+        let scope = ShortcutScope::Focused(Uuid::new_v4());
+        let shortcut = |value| Shortcut::parse(value).unwrap();
+
+        provider.register(
+            Uuid::new_v4(),
+            shortcut("g g"),
+            ShortcutScope::Global,
+            || {},
+        );
+        provider.register(Uuid::new_v4(), shortcut("g"), ShortcutScope::Global, || {});
+        provider.register(Uuid::new_v4(), shortcut("g g"), scope, || {});
+        provider.register(Uuid::new_v4(), shortcut("Ctrl+K"), scope, || {});
+        provider.register(Uuid::new_v4(), shortcut("Ctrl+K"), scope, || {});
+
+        let conflicts = provider.conflicts();
+        assert_eq!(conflicts.len(), 2);
+        assert_eq!(conflicts[0].scope, ShortcutScope::Global);
+        assert_eq!(conflicts[0].first.to_string(), "g");
+        assert_eq!(conflicts[0].second.to_string(), "g g");
+        assert_eq!(conflicts[1].scope, scope);
+        assert_eq!(conflicts[1].first.to_string(), "Ctrl+K");
+    }
+
+    #[tokio::test]
+    pub async fn trigger_shortcuts() {
+        #[allow(non_snake_case)]
+        #[inline_props]
+        fn Editor(cx: Scope, last: UseState<&'static str>) -> Element {
+            let shortcut_scope = use_shortcut_scope(cx);
+            let focus = use_node_focus(cx);
+
+            use_shortcut(cx, "g g", shortcut_scope.scope(), {
+                to_owned![last];
+                move || last.set("editor g g")
+            });
+
+            render!(rect {
+                width: "100%",
+                height: "50%",
+                focus_reference: focus.attribute(cx),
+                onclick: move |_| focus.focus(),
+                onkeydown: move |e: KeyboardEvent| shortcut_scope.enter(&e.data),
+            })
+        }
+
+        fn use_shortcut_app(cx: Scope) -> Element {
+            let shortcuts = use_init_shortcut_provider(cx);
+            let last = use_state(cx, || "none");
+
+            use_shortcut(cx, "Ctrl+Shift+P", ShortcutScope::Global, {
+                to_owned![last];
+                move || last.set("palette")
+            });
+            use_shortcut(cx, "g g", ShortcutScope::Global, {
+                to_owned![last];
+                move || last.set("global g g")
+            });
+
+            render!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    onkeydown: move |e: KeyboardEvent| {
+                        shortcuts.process_key(&e.data);
+                    },
+                    label {
+                        "{last}"
+                    }
+                    Editor {
+                        last: last.clone()
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            use_shortcut_app,
+            TestingConfig::default().with_size((100.0, 100.0).into()),
+        );
+
+        utils.wait_for_update().await;
+        let root = utils.root().get(0);
+        let label = root.get(0);
+        assert_eq!(label.get(0).text(), Some("none"));
+
+        // A chord
+        utils.push_event(key_down(
+            Key::Character("P".to_string()),
+            Code::KeyP,
+            Modifiers::CONTROL | Modifiers::SHIFT,
+        ));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("palette"));
+
+        // A sequence, nothing is focused so only the global one is active
+        let g = || {
+            key_down(
+                Key::Character("g".to_string()),
+                Code::KeyG,
+                Modifiers::empty(),
+            )
+        };
+        utils.push_event(g());
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("palette"));
+        utils.push_event(g());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("global g g"));
+
+        // Focus the editor
+        utils.push_event(FreyaEvent::Mouse {
+            name: "click".to_string(),
+            cursor: (5.0, 75.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The shortcut of the focused scope takes precedence
+        utils.push_event(g());
+        utils.wait_for_update().await;
+        utils.push_event(g());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("editor g g"));
+    }
+
+    #[tokio::test]
+    pub async fn ignore_invalid_shortcuts() {
+        fn invalid_shortcut_app(cx: Scope) -> Element {
+            let shortcuts = use_init_shortcut_provider(cx);
+            let last = use_state(cx, || "none");
+
+            use_shortcut(cx, "Ctrl+", ShortcutScope::Global, {
+                to_owned![last];
+                move || last.set("invalid")
+            });
+            use_shortcut(cx, "Ctrl+K", ShortcutScope::Global, {
+                to_owned![last];
+                move || last.set("valid")
+            });
+
+            render!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    onkeydown: move |e: KeyboardEvent| {
+                        shortcuts.process_key(&e.data);
+                    },
+                    label {
+                        "{last}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            invalid_shortcut_app,
+            TestingConfig::default().with_size((100.0, 100.0).into()),
+        );
+
+        utils.wait_for_update().await;
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("none"));
+
+        // The invalid shortcut doesn't stop the others from working
+        utils.push_event(key_down(
+            Key::Character("k".to_string()),
+            Code::KeyK,
+            Modifiers::CONTROL,
+        ));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("valid"));
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use dioxus_core::{AttributeValue, Scope, ScopeState};
+use dioxus_hooks::{use_memo, UseFutureDep};
+use freya_node_state::{CustomAttributeValues, Stylesheet};
+
+/// Holds a [`Stylesheet`] that can be passed to the `stylesheet` attribute.
+pub struct UseStylesheet {
+    stylesheet: Arc<Stylesheet>,
+}
+
+impl PartialEq for UseStylesheet {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.stylesheet, &other.stylesheet)
+    }
+}
+
+impl UseStylesheet {
+    pub fn attribute<'a, T>(&self, cx: Scope<'a, T>) -> AttributeValue<'a> {
+        cx.any_value(CustomAttributeValues::Stylesheet(self.stylesheet.clone()))
+    }
+}
+
+/// Create a [`Stylesheet`] for the elements under the one with the `stylesheet` attribute.
+/// It will only be created again when the dependencies change.
+///
+/// ## Usage
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app(cx: Scope) -> Element {
+///     let stylesheet = use_stylesheet(cx, (), |_| {
+///         Stylesheet::new()
+///             .with_rule(".card", &[("background", "rgb(230, 230, 230)"), ("padding", "10")])
+///             .with_rule("label.title", &[("font_size", "20")])
+///     });
+///
+///     render!(
+///         rect {
+///             stylesheet: stylesheet.attribute(cx),
+///             rect {
+///                 class: "card",
+///                 label {
+///                     class: "title",
+///                     "Hello, World!"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_stylesheet<D>(
+    cx: &ScopeState,
+    dependencies: D,
+    stylesheet_cb: impl FnOnce(D::Out) -> Stylesheet,
+) -> UseStylesheet
+where
+    D: UseFutureDep,
+{
+    let stylesheet = use_memo(cx, dependencies, |dependencies| {
+        Arc::new(stylesheet_cb(dependencies))
+    });
+
+    UseStylesheet {
+        stylesheet: stylesheet.clone(),
+    }
+}
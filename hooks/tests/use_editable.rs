@@ -478,3 +478,86 @@ pub async fn highlights_single_line_mulitple_editors() {
 
     assert_eq!(highlights_2, Some(vec![(start, end)]));
 }
+
+#[tokio::test]
+pub async fn ime_composition() {
+    fn use_editable_app(cx: Scope) -> Element {
+        let editable = use_editable(
+            cx,
+            || EditableConfig::new("Hello".to_string()).with_cursor((0, 5)),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let editor = editable.editor();
+
+        let oncomposition = {
+            to_owned![editable];
+            move |e: CompositionEvent| {
+                editable.process_event(&EditableEvent::Composition(e.data));
+            }
+        };
+
+        let oncompositionend = {
+            to_owned![editable];
+            move |_: CompositionEvent| {
+                editable.process_event(&EditableEvent::CompositionEnd);
+            }
+        };
+
+        let ontextinput = {
+            to_owned![editable];
+            move |e: TextInputEvent| {
+                editable.process_event(&EditableEvent::TextInput(e.data));
+            }
+        };
+
+        let preedit = editor.preedit().unwrap_or("none");
+
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                oncompositionstart: oncomposition.clone(),
+                oncompositionupdate: oncomposition,
+                oncompositionend: oncompositionend,
+                ontextinput: ontextinput,
+                label {
+                    "{editor}"
+                }
+                label {
+                    "{preedit}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_editable_app);
+
+    let root = utils.root().get(0);
+    let content = root.get(0).get(0);
+    let preedit = root.get(1).get(0);
+
+    let composition = |name: &str, data: &str| FreyaEvent::Composition {
+        name: name.to_string(),
+        data: data.to_string(),
+        cursor: None,
+    };
+
+    utils.push_event(composition("compositionstart", ""));
+    utils.wait_for_update().await;
+    utils.push_event(composition("compositionupdate", "にほ"));
+    utils.wait_for_update().await;
+
+    // The composed text is not part of the content until it's committed
+    assert_eq!(content.text(), Some("Hello"));
+    assert_eq!(preedit.text(), Some("にほ"));
+
+    utils.push_event(composition("compositionend", "日本"));
+    utils.push_event(FreyaEvent::TextInput {
+        name: "textinput".to_string(),
+        text: "日本".to_string(),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(content.text(), Some("Hello日本"));
+    assert_eq!(preedit.text(), Some("none"));
+}
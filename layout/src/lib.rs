@@ -7,12 +7,13 @@ use dioxus_native_core::{
 };
 use freya_common::CursorLayoutResponse;
 use freya_dom::prelude::{DioxusDOM, DioxusNode};
-use freya_node_state::{CursorReference, CursorSettings, FontStyle, References};
+use freya_node_state::{CursorReference, CursorSettings, FontStyle, References, Style};
 
 pub use layers::*;
 use skia_safe::textlayout::{
     FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, TextStyle,
 };
+use skia_safe::{BlendMode, Paint};
 use torin::{
     geometry::{Area, CursorPoint},
     prelude::{LayoutMeasurer, Node, Size2D},
@@ -70,7 +71,7 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
 }
 
 /// Collect all the texts and FontStyles from all the given Node's children
-pub fn get_inner_texts(node: &DioxusNode) -> Vec<(FontStyle, String)> {
+pub fn get_inner_texts(node: &DioxusNode) -> Vec<(FontStyle, Option<BlendMode>, String)> {
     node.children()
         .iter()
         .filter_map(|child| {
@@ -85,7 +86,8 @@ pub fn get_inner_texts(node: &DioxusNode) -> Vec<(FontStyle, String)> {
 
                 if let NodeType::Text(TextNode { text, .. }) = child_text_type {
                     let font_style = child.get::<FontStyle>().unwrap();
-                    Some((font_style.clone(), text.to_owned()))
+                    let style = child.get::<Style>().unwrap();
+                    Some((font_style.clone(), style.blend_mode, text.to_owned()))
                 } else {
                     None
                 }
@@ -109,15 +111,26 @@ fn create_text(
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
 
+    if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
+        paragraph_style.set_ellipsis(ellipsis);
+    }
+
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
-    paragraph_builder.push_style(
-        TextStyle::new()
-            .set_font_style(font_style.to_skia_font_style())
-            .set_font_size(font_style.font_size)
-            .set_font_families(&font_style.font_family),
-    );
-    
+    let mut text_style = TextStyle::new();
+    text_style
+        .set_font_style(font_style.to_skia_font_style())
+        .set_font_size(font_style.font_size)
+        .set_font_families(&font_style.font_family)
+        .set_letter_spacing(font_style.letter_spacing)
+        .set_word_spacing(font_style.word_spacing);
+    text_style.set_decoration(&font_style.decoration);
+    for text_shadow in font_style.text_shadows.iter() {
+        text_style.add_shadow(*text_shadow);
+    }
+
+    paragraph_builder.push_style(&text_style);
+
     paragraph_builder.add_text(text);
 
     let mut paragraph = paragraph_builder.build();
@@ -140,27 +153,49 @@ pub fn create_paragraph(
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
 
+    if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
+        paragraph_style.set_ellipsis(ellipsis);
+    }
+
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
     paragraph_builder.push_style(
         TextStyle::new()
             .set_font_style(font_style.to_skia_font_style())
             .set_font_size(font_style.font_size)
-            .set_font_families(&font_style.font_family),
+            .set_font_families(&font_style.font_family)
+            .set_letter_spacing(font_style.letter_spacing)
+            .set_word_spacing(font_style.word_spacing),
     );
 
     let texts = get_inner_texts(node);
 
-    for (font_style, text) in texts.into_iter() {
-        paragraph_builder.push_style(
-            TextStyle::new()
-                .set_font_style(font_style.to_skia_font_style())
-                .set_height_override(true)
-                .set_height(font_style.line_height)
-                .set_color(font_style.color)
-                .set_font_size(font_style.font_size)
-                .set_font_families(&font_style.font_family),
-        );
+    for (font_style, blend_mode, text) in texts.into_iter() {
+        let mut text_style = TextStyle::new();
+        text_style
+            .set_font_style(font_style.to_skia_font_style())
+            .set_height_override(true)
+            .set_height(font_style.line_height)
+            .set_color(font_style.color)
+            .set_font_size(font_style.font_size)
+            .set_font_families(&font_style.font_family)
+            .set_letter_spacing(font_style.letter_spacing)
+            .set_word_spacing(font_style.word_spacing);
+        text_style.set_decoration(&font_style.decoration);
+        for text_shadow in font_style.text_shadows.iter() {
+            text_style.add_shadow(*text_shadow);
+        }
+
+        // Text spans are not rendered on their own, so they blend through their paint
+        if let Some(blend_mode) = blend_mode {
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_color(font_style.color);
+            paint.set_blend_mode(blend_mode);
+            text_style.set_foreground_color(&paint);
+        }
+
+        paragraph_builder.push_style(&text_style);
         paragraph_builder.add_text(text);
     }
 
@@ -169,7 +204,6 @@ pub fn create_paragraph(
         paragraph_builder.add_text(" ");
     }
 
-
     let mut paragraph = paragraph_builder.build();
     paragraph.layout(node_area.width() + 1.0);
     paragraph
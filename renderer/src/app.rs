@@ -1,23 +1,31 @@
-use std::{collections::HashMap, sync::Arc, task::Waker};
+use std::{collections::HashMap, sync::Arc, task::Waker, time::Instant};
 
 use dioxus_core::{Template, VirtualDom};
+use dioxus_native_core::NodeId;
 use freya_common::EventMessage;
 use freya_core::prelude::*;
-use freya_dom::prelude::SafeDOM;
+use freya_dom::prelude::{DioxusDOMAdapter, SafeDOM};
 use freya_layout::Layers;
 use futures::FutureExt;
 use futures::{
     pin_mut,
     task::{self, ArcWake},
 };
-use skia_safe::{textlayout::FontCollection, FontMgr};
+use skia_safe::{textlayout::FontCollection, Rect};
 use tokio::{
     select,
     sync::{mpsc::unbounded_channel, Notify},
 };
 use uuid::Uuid;
-use winit::{dpi::PhysicalSize, event_loop::EventLoopProxy};
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    event_loop::EventLoopProxy,
+};
 
+use crate::assets_cache::{AssetsCache, SharedAssetsCacheStats};
+use crate::elements::editable_cursor_rect;
+use crate::image_playbacks::ImagePlaybacks;
+use crate::transitions::Transitions;
 use crate::{HoveredNode, WindowEnv};
 
 pub fn winit_waker(proxy: &EventLoopProxy<EventMessage>) -> std::task::Waker {
@@ -56,6 +64,15 @@ pub struct App<State: 'static + Clone> {
     viewports_collection: ViewportsCollection,
 
     font_collection: FontCollection,
+    assets_cache: AssetsCache,
+    image_playbacks: ImagePlaybacks,
+    transitions: Transitions,
+    /// When the next rerender for the animated images is scheduled.
+    scheduled_rerender: Option<Instant>,
+    /// Cursor of the focused editable text, where the input method windows are placed.
+    ime_cursor_area: Option<Rect>,
+    /// Focused element the cursor of the input methods was measured for, `None` if it must be measured again.
+    ime_cursor_focus: Option<Option<NodeId>>,
 }
 
 impl<State: 'static + Clone> App<State> {
@@ -65,9 +82,18 @@ impl<State: 'static + Clone> App<State> {
         proxy: &EventLoopProxy<EventMessage>,
         mutations_notifier: Option<Arc<Notify>>,
         window_env: WindowEnv<State>,
+        assets_cache_stats: SharedAssetsCacheStats,
     ) -> Self {
-        let mut font_collection = FontCollection::new();
-        font_collection.set_default_font_manager(FontMgr::default(), "Fira Sans");
+        let font_collection = create_font_collection(
+            &window_env.window_config.fonts,
+            window_env.window_config.default_font,
+        );
+        let assets_cache = AssetsCache::new(
+            window_env.window_config.assets_cache_size,
+            assets_cache_stats,
+        );
+        let events_processor = EventsProcessor::default()
+            .with_double_click_interval(window_env.window_config.double_click_interval);
         let (event_emitter, event_receiver) = unbounded_channel::<DomEvent>();
         Self {
             rdom,
@@ -80,9 +106,15 @@ impl<State: 'static + Clone> App<State> {
             event_receiver,
             window_env,
             layers: Layers::default(),
-            events_processor: EventsProcessor::default(),
+            events_processor,
             viewports_collection: HashMap::default(),
             font_collection,
+            assets_cache,
+            image_playbacks: ImagePlaybacks::default(),
+            transitions: Transitions::default(),
+            scheduled_rerender: None,
+            ime_cursor_area: None,
+            ime_cursor_focus: None,
         }
     }
 
@@ -118,7 +150,11 @@ impl<State: 'static + Clone> App<State> {
             && mutations.templates.is_empty();
 
         let (repaint, relayout) = if !is_empty {
-            self.rdom.get_mut().apply_mutations(mutations, scale_factor)
+            let changes = self.rdom.get_mut().apply_mutations(mutations, scale_factor);
+            self.events_processor.prune_removed_nodes(&self.rdom.get());
+            // The text or the cursor of the focused element might have changed
+            self.ime_cursor_focus = None;
+            changes
         } else {
             (false, false)
         };
@@ -142,7 +178,8 @@ impl<State: 'static + Clone> App<State> {
                 let fut = async {
                     select! {
                         ev = self.event_receiver.recv() => {
-                            if let Some(ev) = ev {
+                            // Skip the listeners of events stopped by a previous listener
+                            if let Some(ev) = ev.filter(|ev| !ev.is_propagation_stopped()) {
                                 let data = ev.data.any();
                                 self.vdom.handle_event(&ev.name, data, ev.element_id, false);
 
@@ -170,12 +207,24 @@ impl<State: 'static + Clone> App<State> {
                     .unwrap();
             }
         }
+
+        // The listeners of the processed events ran, so the focus can move unless they prevented it
+        let focus_changed = apply_pending_focus(
+            &self.rdom.get(),
+            &mut self.events_processor,
+            &self.event_emitter,
+        );
+        if focus_changed {
+            self.proxy
+                .send_event(EventMessage::RequestRerender)
+                .unwrap();
+        }
     }
 
     /// Process the events queue
     pub fn process_events(&mut self) {
         let scale_factor = self.window_env.window.scale_factor();
-        process_events(
+        let must_repaint = process_events(
             &self.rdom.get(),
             &self.layers,
             &mut self.events,
@@ -183,17 +232,58 @@ impl<State: 'static + Clone> App<State> {
             &mut self.events_processor,
             &self.viewports_collection,
             scale_factor,
-        )
+        );
+
+        // Elements whose hovered, pressed or focused styles changed don't need a new layout
+        if must_repaint {
+            self.proxy
+                .send_event(EventMessage::RequestRerender)
+                .unwrap();
+        }
+    }
+
+    /// Move the keyboard focus to the Node bound to the given focus reference
+    pub fn focus_node(&mut self, focus_id: Uuid) {
+        let must_repaint = focus_reference(
+            focus_id,
+            &self.rdom.get(),
+            &mut self.events_processor,
+            &self.event_emitter,
+        );
+
+        if must_repaint {
+            self.proxy
+                .send_event(EventMessage::RequestRerender)
+                .unwrap();
+        }
     }
 
     /// Measure the layout
     pub fn process_layout(&mut self) {
+        // Measure the Nodes whose size is being transitioned with their current size
+        self.transitions.update(
+            &self.rdom.get(),
+            &self.layers,
+            self.events_processor.interaction_states(),
+        );
+        self.rdom
+            .get_mut()
+            .set_size_overrides(self.transitions.size_overrides());
+
         let dom = self.rdom.get();
+
+        let dom_adapter = DioxusDOMAdapter::new(dom.rdom());
+        for node_id in self.transitions.take_relayout_nodes() {
+            dom.layout()
+                .check_dirty_dependants(node_id, &dom_adapter, false);
+        }
+
         let (layers, viewports) = self
             .window_env
             .process_layout(&dom, &mut self.font_collection);
         self.layers = layers;
         self.viewports_collection = viewports;
+        self.ime_cursor_focus = None;
 
         if let Some(mutations_notifier) = &self.mutations_notifier {
             mutations_notifier.notify_one();
@@ -212,13 +302,94 @@ impl<State: 'static + Clone> App<State> {
 
     /// Render the RealDOM into the Window
     pub fn render(&mut self, hovered_node: &HoveredNode) {
+        let now = Instant::now();
+        if self.scheduled_rerender.map(|t| t <= now).unwrap_or(false) {
+            self.scheduled_rerender = None;
+        }
+
+        let dom = self.rdom.get();
+
+        self.transitions.update(
+            &dom,
+            &self.layers,
+            self.events_processor.interaction_states(),
+        );
+
         self.window_env.render(
             &self.layers,
             &self.viewports_collection,
             &mut self.font_collection,
             hovered_node,
-            &self.rdom.get(),
+            &dom,
+            &mut self.assets_cache,
+            &mut self.image_playbacks,
+            self.events_processor.interaction_states(),
+            &self.transitions,
         );
+
+        self.image_playbacks
+            .retain_nodes(|node_id| dom.rdom().get(node_id).is_some());
+
+        // Pointer events hit the elements where they were just painted
+        self.events_processor
+            .set_animated_transforms(self.transitions.animated_transforms());
+
+        // Only measure the cursor again when the layout, the focused element or its cursor changed
+        let focused_node = self.events_processor.focused_node();
+        let ime_cursor_area = (self.ime_cursor_focus != Some(focused_node)).then(|| {
+            focused_node
+                .and_then(|node_id| editable_cursor_rect(node_id, &dom, &self.font_collection))
+        });
+        drop(dom);
+
+        if let Some(ime_cursor_area) = ime_cursor_area {
+            self.set_ime_cursor_area(ime_cursor_area);
+            self.ime_cursor_focus = Some(focused_node);
+        }
+
+        if let Some(next_frame) = self.image_playbacks.take_next_frame() {
+            self.schedule_rerender(next_frame);
+        }
+
+        // Keep rendering frames until all the transitions are finished
+        if self.transitions.must_relayout() {
+            self.window_env.request_redraw();
+        } else if self.transitions.is_running() {
+            self.proxy
+                .send_event(EventMessage::RequestRerender)
+                .unwrap();
+        }
+    }
+
+    /// Enable the input methods while an editable text is focused and place their windows next to its cursor.
+    fn set_ime_cursor_area(&mut self, ime_cursor_area: Option<Rect>) {
+        if ime_cursor_area == self.ime_cursor_area {
+            return;
+        }
+
+        let window = &self.window_env.window;
+        if ime_cursor_area.is_some() != self.ime_cursor_area.is_some() {
+            window.set_ime_allowed(ime_cursor_area.is_some());
+        }
+        if let Some(area) = ime_cursor_area {
+            window.set_ime_position(PhysicalPosition::new(area.left, area.bottom));
+        }
+
+        self.ime_cursor_area = ime_cursor_area;
+    }
+
+    /// Request a rerender at the given time, unless there is an earlier one already scheduled.
+    fn schedule_rerender(&mut self, at: Instant) {
+        if self.scheduled_rerender.map(|t| t <= at).unwrap_or(false) {
+            return;
+        }
+        self.scheduled_rerender = Some(at);
+
+        let proxy = self.proxy.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep_until(at.into()).await;
+            _ = proxy.send_event(EventMessage::RequestRerender);
+        });
     }
 
     /// Resize the Window
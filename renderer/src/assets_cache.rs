@@ -0,0 +1,505 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::Bytes;
+use freya_node_state::{AssetData, ImageReference};
+use skia_safe::{codec, svg, Codec, Data, Image};
+use tracing::warn;
+
+use crate::elements::{SvgColors, SvgTemplate};
+
+/// Default max size of the decoded assets, 128MB.
+pub const DEFAULT_ASSETS_CACHE_SIZE: usize = 128 * 1024 * 1024;
+
+/// Usage statistics of the [`AssetsCache`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssetsCacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+    pub entries: usize,
+    /// Estimated size in bytes of all the cached assets.
+    pub size: usize,
+}
+
+/// Shared handle to the [`AssetsCacheStats`] so they can be read from outside the renderer, e.g the devtools.
+pub type SharedAssetsCacheStats = Arc<Mutex<AssetsCacheStats>>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum AssetKey {
+    /// Hash of the encoded content, see [`AssetData`].
+    Content(u64),
+    /// Hash of the source of a SVG.
+    Svg(u64),
+    /// Identity of an [`ImageReference`] and its current bytes.
+    Reference { reference: usize, data: usize },
+}
+
+/// A frame of a [`DecodedImage`].
+pub struct ImageFrame {
+    pub image: Image,
+    pub duration: Duration,
+}
+
+/// A decoded image, animated images (e.g GIF, APNG or WebP) have more than one frame.
+pub struct DecodedImage {
+    frames: Vec<ImageFrame>,
+}
+
+impl DecodedImage {
+    /// Decode all the frames of an image.
+    /// Animations that would not fit in the cache only show their first frame.
+    fn decode(bytes: &[u8], max_size: usize) -> Option<Self> {
+        let mut codec = Codec::from_data(Data::new_copy(bytes))?;
+        let frames_info = codec.get_frame_info();
+
+        let info = codec.info();
+        let row_bytes = info.min_row_bytes();
+        let frame_size = info.compute_min_byte_size();
+
+        let is_animated = frames_info.len() > 1;
+        let fits_in_cache = frame_size * frames_info.len() <= max_size;
+
+        if is_animated && !fits_in_cache {
+            warn!(
+                "Animated image of {} frames doesn't fit in the assets cache, only its first frame will be shown",
+                frames_info.len()
+            );
+        }
+
+        if !is_animated || !fits_in_cache {
+            let image = Image::from_encoded(Data::new_copy(bytes))?;
+            return Some(Self {
+                frames: vec![ImageFrame {
+                    image,
+                    duration: Duration::ZERO,
+                }],
+            });
+        }
+
+        let mut pixels = vec![0; frame_size];
+        let mut frames_pixels: Vec<Data> = Vec::with_capacity(frames_info.len());
+        let mut frames = Vec::with_capacity(frames_info.len());
+
+        for (frame_index, frame_info) in frames_info.into_iter().enumerate() {
+            // Frames are decoded on top of the frame they depend on, if any.
+            // Its disposal (e.g restoring the background) is applied by the codec.
+            let prior_frame = usize::try_from(frame_info.required_frame)
+                .ok()
+                .filter(|prior_frame| *prior_frame < frames_pixels.len());
+            if let Some(prior_frame) = prior_frame {
+                pixels.copy_from_slice(frames_pixels[prior_frame].as_bytes());
+            } else {
+                pixels.fill(0);
+            }
+
+            let options = codec::Options {
+                zero_initialized: codec::ZeroInitialized::Yes,
+                subset: None,
+                frame_index,
+                prior_frame,
+            };
+            let result =
+                codec.get_pixels_with_options(&info, &mut pixels, row_bytes, Some(&options));
+
+            let frame_pixels = if matches!(
+                result,
+                codec::Result::Success | codec::Result::IncompleteInput
+            ) {
+                Data::new_copy(&pixels)
+            } else if let Some(previous_pixels) = frames_pixels.last() {
+                // Keep showing the previous frame for as long as the broken one should be shown
+                warn!("Frame {frame_index} of an animated image could not be decoded");
+                previous_pixels.clone()
+            } else {
+                return None;
+            };
+
+            let image = Image::from_raster_data(&info, frame_pixels.clone(), row_bytes)?;
+            frames_pixels.push(frame_pixels);
+
+            // Like browsers do, very short frames are slowed down
+            let duration = if frame_info.duration <= 10 {
+                100
+            } else {
+                frame_info.duration as u64
+            };
+            frames.push(ImageFrame {
+                image,
+                duration: Duration::from_millis(duration),
+            });
+        }
+
+        Some(Self { frames })
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.frames.len() > 1
+    }
+
+    /// Total duration of all the frames.
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.duration).sum()
+    }
+
+    pub fn first_frame(&self) -> &ImageFrame {
+        &self.frames[0]
+    }
+
+    /// Get the frame shown at the given position and how long until the next frame.
+    pub fn frame_at(&self, position: Duration) -> (&ImageFrame, Duration) {
+        let mut elapsed = Duration::ZERO;
+        for frame in &self.frames {
+            elapsed += frame.duration;
+            if position < elapsed {
+                return (frame, elapsed - position);
+            }
+        }
+        (self.frames.last().unwrap(), Duration::ZERO)
+    }
+
+    fn size(&self) -> usize {
+        self.frames
+            .iter()
+            .map(|frame| frame.image.width() as usize * frame.image.height() as usize * 4)
+            .sum()
+    }
+}
+
+/// Max number of differently colored variants of a SVG that are kept.
+const MAX_SVG_VARIANTS: usize = 4;
+
+/// A SVG parsed once, and its variants painted with the most recently used colors.
+struct CachedSvg {
+    template: SvgTemplate,
+    /// Least recently used first.
+    variants: Vec<(SvgColors, Option<svg::Dom>)>,
+}
+
+impl CachedSvg {
+    fn variant(&mut self, colors: &SvgColors) -> Option<&mut svg::Dom> {
+        let index = self
+            .variants
+            .iter()
+            .position(|(variant_colors, _)| self.template.same_colors(variant_colors, colors));
+
+        let variant = if let Some(index) = index {
+            self.variants.remove(index)
+        } else {
+            if self.variants.len() == MAX_SVG_VARIANTS {
+                self.variants.remove(0);
+            }
+            let source = self.template.apply(colors);
+            (*colors, svg::Dom::from_bytes(source.as_bytes()).ok())
+        };
+
+        self.variants.push(variant);
+        self.variants
+            .last_mut()
+            .and_then(|(_, svg_dom)| svg_dom.as_mut())
+    }
+}
+
+enum Asset {
+    Image(DecodedImage),
+    Svg(CachedSvg),
+    /// Content that could not be decoded, kept so it's not decoded again every frame.
+    Invalid,
+}
+
+struct CachedAsset {
+    asset: Asset,
+    size: usize,
+    last_used: u64,
+    /// Keeps the bytes of [`AssetKey::Reference`] alive so their address can't be reused by other bytes.
+    _source: Option<Bytes>,
+}
+
+/// Keeps the decoded images and parsed SVGs so they are not decoded again every frame.
+/// The least recently used assets are evicted once the max size is exceeded.
+pub struct AssetsCache {
+    assets: HashMap<AssetKey, CachedAsset>,
+    max_size: usize,
+    size: usize,
+    tick: u64,
+    stats: SharedAssetsCacheStats,
+}
+
+impl AssetsCache {
+    pub fn new(max_size: usize, stats: SharedAssetsCacheStats) -> Self {
+        Self {
+            assets: HashMap::default(),
+            max_size,
+            size: 0,
+            tick: 0,
+            stats,
+        }
+    }
+
+    /// Get the decoded image of some encoded bytes.
+    pub fn image(&mut self, data: &AssetData) -> Option<&DecodedImage> {
+        self.image_by_key(AssetKey::Content(data.hash()), data.bytes(), None)
+    }
+
+    /// Get the decoded image of an [`ImageReference`] with the given bytes.
+    pub fn image_reference(
+        &mut self,
+        image_ref: &ImageReference,
+        bytes: &Bytes,
+    ) -> Option<&DecodedImage> {
+        let reference = Arc::as_ptr(&image_ref.0) as usize;
+        let key = AssetKey::Reference {
+            reference,
+            data: bytes.as_ptr() as usize,
+        };
+
+        // Forget the previous images of this reference as they will not be used again
+        if !self.assets.contains_key(&key) {
+            self.remove_where(
+                |key| matches!(key, AssetKey::Reference { reference: r, .. } if *r == reference),
+            );
+        }
+
+        self.image_by_key(key, bytes, Some(bytes.clone()))
+    }
+
+    /// Get the parsed SVG of some bytes, painted with the given colors.
+    /// The source is only parsed once, other colors reuse it.
+    pub fn svg(&mut self, data: &AssetData, colors: &SvgColors) -> Option<&mut svg::Dom> {
+        let key = AssetKey::Svg(data.hash());
+        self.tick += 1;
+
+        if self.assets.contains_key(&key) {
+            self.record(|stats| stats.hits += 1);
+        } else {
+            self.record(|stats| stats.misses += 1);
+            let template = SvgTemplate::parse(&String::from_utf8_lossy(data.bytes()));
+            let asset = Asset::Svg(CachedSvg {
+                template,
+                variants: Vec::new(),
+            });
+            // Estimate the size of all its variants by the size of the source
+            self.insert(key, asset, data.bytes().len() * MAX_SVG_VARIANTS, None);
+        }
+
+        let cached = self.assets.get_mut(&key)?;
+        cached.last_used = self.tick;
+        match &mut cached.asset {
+            Asset::Svg(cached_svg) => cached_svg.variant(colors),
+            _ => None,
+        }
+    }
+
+    fn image_by_key(
+        &mut self,
+        key: AssetKey,
+        bytes: &[u8],
+        source: Option<Bytes>,
+    ) -> Option<&DecodedImage> {
+        self.tick += 1;
+
+        if self.assets.contains_key(&key) {
+            self.record(|stats| stats.hits += 1);
+        } else {
+            self.record(|stats| stats.misses += 1);
+            if let Some(image) = DecodedImage::decode(bytes, self.max_size) {
+                let size = image.size();
+                self.insert(key, Asset::Image(image), size, source);
+            } else {
+                self.insert(key, Asset::Invalid, 0, source);
+            }
+        }
+
+        let cached = self.assets.get_mut(&key)?;
+        cached.last_used = self.tick;
+        match &cached.asset {
+            Asset::Image(image) => Some(image),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, key: AssetKey, asset: Asset, size: usize, source: Option<Bytes>) {
+        let mut evictions = 0;
+
+        // Free space for the new asset by removing the least recently used ones
+        while self.size + size > self.max_size && !self.assets.is_empty() {
+            let oldest_key = self
+                .assets
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(key, _)| *key);
+            if let Some(cached) = oldest_key.and_then(|key| self.assets.remove(&key)) {
+                self.size -= cached.size;
+                evictions += 1;
+            }
+        }
+
+        self.size += size;
+        self.assets.insert(
+            key,
+            CachedAsset {
+                asset,
+                size,
+                last_used: self.tick,
+                _source: source,
+            },
+        );
+
+        let (entries, total_size) = (self.assets.len(), self.size);
+        self.record(|stats| {
+            stats.evictions += evictions;
+            stats.entries = entries;
+            stats.size = total_size;
+        });
+    }
+
+    fn remove_where(&mut self, predicate: impl Fn(&AssetKey) -> bool) {
+        let size = &mut self.size;
+        self.assets.retain(|key, cached| {
+            let remove = predicate(key);
+            if remove {
+                *size -= cached.size;
+            }
+            !remove
+        });
+
+        let (entries, total_size) = (self.assets.len(), self.size);
+        self.record(|stats| {
+            stats.entries = entries;
+            stats.size = total_size;
+        });
+    }
+
+    fn record(&self, update: impl FnOnce(&mut AssetsCacheStats)) {
+        update(&mut self.stats.lock().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use bytes::Bytes;
+    use freya_node_state::{AssetData, ImageReference};
+    use skia_safe::{Color, EncodedImageFormat, Surface};
+
+    use super::{AssetsCache, AssetsCacheStats, SharedAssetsCacheStats};
+    use crate::elements::SvgColors;
+
+    /// Encode a PNG of the given size, its decoded size is `width * height * 4` bytes.
+    fn encode_png(width: i32, height: i32) -> AssetData {
+        let mut surface = Surface::new_raster_n32_premul((width, height)).unwrap();
+        surface.canvas().clear(Color::RED);
+        let data = surface
+            .image_snapshot()
+            .encode_to_data(EncodedImageFormat::PNG)
+            .unwrap();
+        AssetData::new(data.as_bytes().to_vec())
+    }
+
+    fn cache(max_size: usize) -> (AssetsCache, SharedAssetsCacheStats) {
+        let stats = SharedAssetsCacheStats::default();
+        (AssetsCache::new(max_size, stats.clone()), stats)
+    }
+
+    #[test]
+    fn hits_and_misses() {
+        let (mut cache, stats) = cache(1024 * 1024);
+        let image = encode_png(10, 10);
+
+        assert!(cache.image(&image).is_some());
+        assert!(cache.image(&image).is_some());
+
+        assert_eq!(
+            *stats.lock().unwrap(),
+            AssetsCacheStats {
+                hits: 1,
+                misses: 1,
+                evictions: 0,
+                entries: 1,
+                size: 400,
+            }
+        );
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        // Room for two 10x10 images
+        let (mut cache, stats) = cache(1000);
+        let (a, b, c) = (encode_png(10, 10), encode_png(10, 11), encode_png(10, 12));
+
+        cache.image(&a);
+        cache.image(&b);
+        // `a` is now more recently used than `b`
+        cache.image(&a);
+        cache.image(&c);
+
+        let current = *stats.lock().unwrap();
+        assert_eq!(current.evictions, 1);
+        assert_eq!(current.entries, 2);
+        assert_eq!(current.size, 400 + 480);
+
+        // `a` is still cached but `b` was evicted
+        cache.image(&a);
+        assert_eq!(stats.lock().unwrap().hits, 2);
+        cache.image(&b);
+        assert_eq!(stats.lock().unwrap().misses, 4);
+    }
+
+    #[test]
+    fn caches_invalid_content() {
+        let (mut cache, stats) = cache(1024 * 1024);
+        let invalid = AssetData::new(vec![1, 2, 3]);
+
+        assert!(cache.image(&invalid).is_none());
+        assert!(cache.image(&invalid).is_none());
+
+        let current = *stats.lock().unwrap();
+        assert_eq!(current.misses, 1);
+        assert_eq!(current.hits, 1);
+        assert_eq!(current.size, 0);
+    }
+
+    #[test]
+    fn replaces_reference_images() {
+        let (mut cache, stats) = cache(1024 * 1024);
+        let image_ref = ImageReference(Arc::new(Mutex::new(None)));
+        let first = Bytes::from(encode_png(10, 10).bytes().to_vec());
+        let second = Bytes::from(encode_png(20, 20).bytes().to_vec());
+
+        assert!(cache.image_reference(&image_ref, &first).is_some());
+        assert!(cache.image_reference(&image_ref, &first).is_some());
+        assert!(cache.image_reference(&image_ref, &second).is_some());
+
+        // The image of the previous bytes is forgotten
+        let current = *stats.lock().unwrap();
+        assert_eq!(current.hits, 1);
+        assert_eq!(current.misses, 2);
+        assert_eq!(current.entries, 1);
+        assert_eq!(current.size, 1600);
+    }
+
+    #[test]
+    fn parses_svgs_once_for_any_colors() {
+        let (mut cache, stats) = cache(1024 * 1024);
+        let svg = AssetData::new(
+            br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><rect width="10" height="10"/></svg>"#
+                .to_vec(),
+        );
+        let colors = |fill| SvgColors {
+            fill: Some(fill),
+            stroke: None,
+            current_color: Color::BLACK,
+        };
+
+        assert!(cache.svg(&svg, &colors(Color::RED)).is_some());
+        assert!(cache.svg(&svg, &colors(Color::BLUE)).is_some());
+        assert!(cache.svg(&svg, &colors(Color::RED)).is_some());
+
+        let current = *stats.lock().unwrap();
+        assert_eq!(current.misses, 1);
+        assert_eq!(current.hits, 2);
+        assert_eq!(current.entries, 1);
+    }
+}
@@ -0,0 +1,52 @@
+use freya_node_state::Style;
+use skia_safe::canvas::SaveLayerRec;
+use skia_safe::{image_filters, Canvas, ClipOp, Paint, RRect, Rect, TileMode};
+use torin::geometry::Area;
+
+/// Blur what has already been rendered behind the given node, inside its rounded shape.
+pub fn render_backdrop_blur(canvas: &mut Canvas, area: &Area, node_style: &Style, radius: f32) {
+    let blur = image_filters::blur(
+        (node_style.backdrop_blur, node_style.backdrop_blur),
+        TileMode::Clamp,
+        None,
+        None,
+    );
+
+    if let Some(blur) = blur {
+        let rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+
+        canvas.save();
+        canvas.clip_rrect(
+            RRect::new_rect_xy(rect, radius, radius),
+            ClipOp::Intersect,
+            true,
+        );
+        canvas.save_layer(&SaveLayerRec::default().bounds(&rect).backdrop(&blur));
+        canvas.restore();
+        canvas.restore();
+    }
+}
+
+/// Start a layer where the content of the given node and its descendants is drawn in isolation,
+/// it will be blurred and blended with what is behind it when restored.
+pub fn start_effects_layer(canvas: &mut Canvas, node_style: &Style) {
+    let blur = if node_style.blur > 0.0 {
+        image_filters::blur(
+            (node_style.blur, node_style.blur),
+            TileMode::Decal,
+            None,
+            None,
+        )
+    } else {
+        None
+    };
+
+    let mut paint = Paint::default();
+    if let Some(blur) = blur {
+        paint.set_image_filter(blur);
+    }
+    if let Some(blend_mode) = node_style.blend_mode {
+        paint.set_blend_mode(blend_mode);
+    }
+    canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+}
@@ -1,35 +1,94 @@
 use dioxus_native_core::real_dom::NodeImmutable;
 use freya_dom::prelude::DioxusNode;
-use freya_node_state::{References, Style};
-use skia_safe::{Canvas, Data, IRect, Image, Paint, Rect};
+use freya_node_state::{References, SamplingMode, Style, TransitionProperty};
+use skia_safe::{
+    Canvas, ClipOp, CubicResampler, FilterMode, MipmapMode, Paint, RRect, Rect, SamplingOptions,
+};
 use torin::geometry::Area;
 
+use crate::assets_cache::{AssetsCache, DecodedImage};
+use crate::image_playbacks::ImagePlaybacks;
+use crate::transitions::Transitions;
+
 /// Render an `image` element
-pub fn render_image(area: &Area, node_ref: &DioxusNode, canvas: &mut Canvas) {
+pub fn render_image(
+    area: &Area,
+    node_ref: &DioxusNode,
+    canvas: &mut Canvas,
+    assets_cache: &mut AssetsCache,
+    image_playbacks: &mut ImagePlaybacks,
+    transitions: &Transitions,
+) {
     let node_style = node_ref.get::<Style>().unwrap();
     let node_references = node_ref.get::<References>().unwrap();
+    let radius = transitions
+        .number(&node_ref.id(), TransitionProperty::Radius)
+        .unwrap_or(node_style.radius);
+
+    let mut draw_img = |image: Option<&DecodedImage>| {
+        if let Some(image) = image {
+            // Animated images show the frame of their current playback position
+            let frame = image_playbacks.frame(
+                node_ref.id(),
+                image,
+                node_style.autoplay,
+                node_style.image_loop,
+                node_references.image_playback.as_ref(),
+            );
+            let pic = &frame.image;
 
-    let mut draw_img = |bytes: &[u8]| {
-        let pic = Image::from_encoded(unsafe { Data::new_bytes(bytes) });
-        if let Some(pic) = pic {
             let mut paint = Paint::default();
             paint.set_anti_alias(true);
-            canvas.draw_image_nine(
+
+            let area_rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+            let image_size = (pic.width() as f32, pic.height() as f32);
+            let area_size = (area.width(), area.height());
+
+            let dst = if let Some(scale) = node_style.image_fit.scale(image_size, area_size) {
+                let width = image_size.0 * scale;
+                let height = image_size.1 * scale;
+                let x = area.min_x() + node_style.image_align.horizontal.offset(width, area_size.0);
+                let y = area.min_y() + node_style.image_align.vertical.offset(height, area_size.1);
+                Rect::from_xywh(x, y, width, height)
+            } else {
+                area_rect
+            };
+
+            // Images that don't fill the whole element might overflow it,
+            // so they are clipped to the element's shape
+            canvas.save();
+            canvas.clip_rrect(
+                RRect::new_rect_xy(area_rect, radius, radius),
+                ClipOp::Intersect,
+                true,
+            );
+            canvas.draw_image_rect_with_sampling_options(
                 pic,
-                IRect::new(0, 0, 0, 0),
-                Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
-                skia_safe::FilterMode::Last,
-                Some(&paint),
+                None,
+                dst,
+                to_sampling_options(node_style.sampling),
+                &paint,
             );
+            canvas.restore();
         }
     };
 
     if let Some(image_ref) = &node_references.image_ref {
         let image_data = image_ref.0.lock().unwrap();
         if let Some(image_data) = image_data.as_ref() {
-            draw_img(image_data)
+            draw_img(assets_cache.image_reference(image_ref, image_data))
         }
     } else if let Some(image_data) = &node_style.image_data {
-        draw_img(image_data)
+        draw_img(assets_cache.image(image_data))
+    }
+}
+
+fn to_sampling_options(sampling: SamplingMode) -> SamplingOptions {
+    match sampling {
+        SamplingMode::Nearest => SamplingOptions::new(FilterMode::Nearest, MipmapMode::None),
+        SamplingMode::Bilinear => SamplingOptions::new(FilterMode::Linear, MipmapMode::None),
+        SamplingMode::Trilinear => SamplingOptions::new(FilterMode::Linear, MipmapMode::Linear),
+        SamplingMode::Mitchell => SamplingOptions::from(CubicResampler::mitchell()),
+        SamplingMode::CatmullRom => SamplingOptions::from(CubicResampler::catmull_rom()),
     }
 }
@@ -43,14 +43,26 @@ pub fn render_label(
 
         let mut paragraph_style = ParagraphStyle::default();
         paragraph_style.set_text_align(node_font_style.align);
+        paragraph_style.set_max_lines(node_font_style.max_lines);
 
-        paragraph_style.set_text_style(
-            TextStyle::new()
-                .set_font_style(node_font_style.to_skia_font_style())
-                .set_color(node_font_style.color)
-                .set_font_size(node_font_style.font_size)
-                .set_font_families(&node_font_style.font_family),
-        );
+        if let Some(ellipsis) = node_font_style.text_overflow.get_ellipsis() {
+            paragraph_style.set_ellipsis(ellipsis);
+        }
+
+        let mut text_style = TextStyle::new();
+        text_style
+            .set_font_style(node_font_style.to_skia_font_style())
+            .set_color(node_font_style.color)
+            .set_font_size(node_font_style.font_size)
+            .set_font_families(&node_font_style.font_family)
+            .set_letter_spacing(node_font_style.letter_spacing)
+            .set_word_spacing(node_font_style.word_spacing);
+        text_style.set_decoration(&node_font_style.decoration);
+        for text_shadow in node_font_style.text_shadows.iter() {
+            text_style.add_shadow(*text_shadow);
+        }
+
+        paragraph_style.set_text_style(&text_style);
         let mut paragraph_builder =
             ParagraphBuilder::new(&paragraph_style, font_collection.clone());
 
@@ -2,10 +2,12 @@ mod image;
 mod label;
 mod paragraph;
 mod rect_container;
+mod shape;
 mod svg;
 
 pub use image::*;
 pub use label::*;
 pub use paragraph::*;
 pub use rect_container::*;
+pub use shape::*;
 pub use svg::*;
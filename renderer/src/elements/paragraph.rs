@@ -1,7 +1,10 @@
+use dioxus_native_core::node::NodeType;
+use dioxus_native_core::prelude::ElementNode;
 use dioxus_native_core::real_dom::NodeImmutable;
-use freya_dom::prelude::DioxusNode;
+use dioxus_native_core::NodeId;
+use freya_dom::prelude::{DioxusNode, FreyaDOM};
 use freya_layout::create_paragraph;
-use freya_node_state::CursorSettings;
+use freya_node_state::{CursorMode, CursorSettings};
 use skia_safe::{
     textlayout::{FontCollection, Paragraph, RectHeightStyle, RectWidthStyle},
     Canvas, Paint, PaintStyle, Rect,
@@ -80,8 +83,21 @@ fn draw_cursor(
 
     let cursor = node_cursor_settings.position?;
     let cursor_color = node_cursor_settings.color;
-    let cursor_position = cursor as usize;
 
+    let cursor_rect = cursor_rect(area, paragraph, cursor as usize)?;
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(cursor_color);
+
+    canvas.draw_rect(cursor_rect, &paint);
+
+    Some(())
+}
+
+/// Get the rect where the cursor of a paragraph is drawn.
+fn cursor_rect(area: &Area, paragraph: &Paragraph, cursor_position: usize) -> Option<Rect> {
     let cursor_rects = paragraph.get_rects_for_range(
         cursor_position..cursor_position + 1,
         RectHeightStyle::Tight,
@@ -95,12 +111,35 @@ fn draw_cursor(
     let x2 = x + 1.0;
     let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
 
-    let mut paint = Paint::default();
-    paint.set_anti_alias(true);
-    paint.set_style(PaintStyle::Fill);
-    paint.set_color(cursor_color);
+    Some(Rect::new(x, y, x2, y2))
+}
 
-    canvas.draw_rect(Rect::new(x, y, x2, y2), &paint);
+/// Get the rect of the cursor of the editable paragraph found in the given element or its descendants.
+pub fn editable_cursor_rect(
+    node_id: NodeId,
+    fdom: &FreyaDOM,
+    font_collection: &FontCollection,
+) -> Option<Rect> {
+    let rdom = fdom.rdom();
+    let mut pending_nodes = vec![rdom.get(node_id)?];
+
+    while let Some(node) = pending_nodes.pop() {
+        let cursor_settings = node.get::<CursorSettings>().unwrap();
+        let is_paragraph = matches!(
+            &*node.node_type(),
+            NodeType::Element(ElementNode { tag, .. }) if tag == "paragraph"
+        );
 
-    Some(())
+        if is_paragraph && cursor_settings.mode == CursorMode::Editable {
+            if let Some(cursor) = cursor_settings.position {
+                let area = fdom.layout().get(node.id())?.area;
+                let paragraph = create_paragraph(&node, &area, font_collection, true);
+                return cursor_rect(&area, &paragraph, cursor as usize);
+            }
+        }
+
+        pending_nodes.extend(node.children().into_iter().rev());
+    }
+
+    None
 }
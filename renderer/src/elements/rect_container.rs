@@ -1,27 +1,38 @@
 use dioxus_native_core::real_dom::NodeImmutable;
+use freya_core::prelude::InteractionState;
 use freya_dom::prelude::DioxusNode;
-use freya_node_state::{References, Style};
+use freya_node_state::{References, Style, TransitionProperty};
 use skia_safe::{
     textlayout::FontCollection, BlurStyle, Canvas, MaskFilter, Paint, PaintStyle, Path,
     PathDirection, Rect,
 };
 use torin::prelude::Area;
 
+use crate::transitions::Transitions;
+
 /// Render a `rect` or a `container` element
 pub fn render_rect_container(
     area: &Area,
     node_ref: &DioxusNode,
     canvas: &mut Canvas,
     font_collection: &FontCollection,
+    interaction_state: InteractionState,
+    transitions: &Transitions,
 ) {
     let node_style = &*node_ref.get::<Style>().unwrap();
 
+    let background = transitions
+        .color(&node_ref.id(), TransitionProperty::Background)
+        .unwrap_or_else(|| interaction_state.background(node_style));
+
     let mut paint = Paint::default();
     paint.set_anti_alias(true);
     paint.set_style(PaintStyle::Fill);
-    paint.set_color(node_style.background);
+    paint.set_color(background);
 
-    let radius = node_style.radius;
+    let radius = transitions
+        .number(&node_ref.id(), TransitionProperty::Radius)
+        .unwrap_or(node_style.radius);
     let radius = if radius < 0.0 { 0.0 } else { radius };
 
     let area = area.to_f32();
@@ -52,6 +63,29 @@ pub fn render_rect_container(
 
     canvas.draw_path(&path, &paint);
 
+    // Border, drawn inside the element
+    if let Some(border) = interaction_state.border(node_style) {
+        let mut border_paint = Paint::default();
+        border_paint.set_anti_alias(true);
+        border_paint.set_style(PaintStyle::Stroke);
+        border_paint.set_stroke_width(border.width);
+        border_paint.set_color(border.color);
+
+        let inset = border.width / 2.0;
+        let border_radius = (radius - inset).max(0.0);
+        canvas.draw_round_rect(
+            Rect::new(
+                area.min_x() + inset,
+                area.min_y() + inset,
+                area.max_x() - inset,
+                area.max_y() - inset,
+            ),
+            border_radius,
+            border_radius,
+            &border_paint,
+        );
+    }
+
     let references = node_ref.get::<References>().unwrap();
 
     if let Some(canvas_ref) = &references.canvas_ref {
@@ -0,0 +1,40 @@
+use dioxus_native_core::real_dom::NodeImmutable;
+use freya_dom::prelude::DioxusNode;
+use freya_node_state::{FontStyle, Shape, Style};
+use skia_safe::{Canvas, Paint, PaintStyle, PathEffect};
+use torin::geometry::Area;
+
+/// Render a `circle`, `line`, `polygon` or `path` element
+pub fn render_shape(area: &Area, node_ref: &DioxusNode, canvas: &mut Canvas) {
+    let node_shape = &*node_ref.get::<Shape>().unwrap();
+    let node_style = &*node_ref.get::<Style>().unwrap();
+    let node_font_style = &*node_ref.get::<FontStyle>().unwrap();
+
+    let path = if let Some(path) = node_shape.to_path(area) {
+        path
+    } else {
+        return;
+    };
+
+    let (fill, stroke) = node_shape.paints(node_style);
+
+    if let Some(fill) = fill {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(fill.resolve(node_font_style.color));
+        canvas.draw_path(&path, &paint);
+    }
+
+    if let Some(stroke) = stroke {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(node_shape.stroke_width);
+        paint.set_color(stroke.resolve(node_font_style.color));
+        if !node_shape.stroke_dash.is_empty() {
+            paint.set_path_effect(PathEffect::dash(&node_shape.stroke_dash, 0.0));
+        }
+        canvas.draw_path(&path, &paint);
+    }
+}
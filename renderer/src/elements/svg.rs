@@ -1,18 +1,39 @@
 use dioxus_native_core::real_dom::NodeImmutable;
 use freya_dom::prelude::DioxusNode;
-use freya_node_state::Style;
-use skia_safe::{svg, Canvas};
+use freya_node_state::{FontStyle, Style};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use skia_safe::{Canvas, Color};
 use torin::geometry::Area;
+use tracing::warn;
+
+use crate::assets_cache::AssetsCache;
 
 /// Render a `svg` element
-pub fn render_svg(area: &Area, node_ref: &DioxusNode, canvas: &mut Canvas) {
+pub fn render_svg(
+    area: &Area,
+    node_ref: &DioxusNode,
+    canvas: &mut Canvas,
+    assets_cache: &mut AssetsCache,
+) {
     let node_style = &*node_ref.get::<Style>().unwrap();
+    let node_font_style = &*node_ref.get::<FontStyle>().unwrap();
+
+    let colors = SvgColors {
+        fill: node_style
+            .fill
+            .map(|fill| fill.resolve(node_font_style.color)),
+        stroke: node_style
+            .stroke
+            .map(|stroke| stroke.resolve(node_font_style.color)),
+        current_color: node_font_style.color,
+    };
 
     let x = area.min_x();
     let y = area.min_y();
     if let Some(svg_data) = &node_style.svg_data {
-        let svg_dom = svg::Dom::from_bytes(svg_data);
-        if let Ok(mut svg_dom) = svg_dom {
+        let svg_dom = assets_cache.svg(svg_data, &colors);
+        if let Some(svg_dom) = svg_dom {
             canvas.save();
             canvas.translate((x, y));
             svg_dom.set_container_size((area.width() as i32, area.height() as i32));
@@ -21,3 +42,427 @@ pub fn render_svg(area: &Area, node_ref: &DioxusNode, canvas: &mut Canvas) {
         }
     }
 }
+
+/// Properties whose values are colors, so they can be `currentColor`.
+const PAINT_PROPERTIES: [&str; 6] = [
+    "fill",
+    "stroke",
+    "stop-color",
+    "flood-color",
+    "lighting-color",
+    "color",
+];
+
+/// Colors a SVG is painted with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SvgColors {
+    /// Replaces every fill that is not `none`.
+    pub fill: Option<Color>,
+    /// Replaces every stroke that is not `none`.
+    pub stroke: Option<Color>,
+    /// Resolves `currentColor`.
+    pub current_color: Color,
+}
+
+impl SvgColors {
+    /// The paints that are overridden, with their color.
+    fn paints(&self) -> impl Iterator<Item = (&'static str, Color)> {
+        [("fill", self.fill), ("stroke", self.stroke)]
+            .into_iter()
+            .filter_map(|(paint, color)| Some((paint, color?)))
+    }
+}
+
+/// Source of a SVG split around the parts that depend on the [`SvgColors`],
+/// so it's parsed only once and can then be painted with any colors.
+pub struct SvgTemplate {
+    segments: Vec<Segment>,
+    uses_current_color: bool,
+}
+
+enum Segment {
+    /// Source that is the same for any colors.
+    Source(String),
+    /// Start or empty tag of an element.
+    Tag(Tag),
+    /// Content of a `<style>` element.
+    Stylesheet(String),
+}
+
+struct Tag {
+    name: String,
+    /// Attributes with their raw (escaped) values.
+    attributes: Vec<(String, String)>,
+    is_root: bool,
+    is_empty: bool,
+}
+
+impl SvgTemplate {
+    /// Parse the source of a SVG.
+    /// Sources that are not valid XML are kept as they are and can't be recolored.
+    pub fn parse(source: &str) -> Self {
+        let uses_current_color = source.to_ascii_lowercase().contains("currentcolor");
+        let segments = parse_segments(source).unwrap_or_else(|err| {
+            warn!("SVG could not be parsed to apply its colors: {err}");
+            vec![Segment::Source(source.to_string())]
+        });
+
+        Self {
+            segments,
+            uses_current_color,
+        }
+    }
+
+    /// Whether painting the SVG with `a` and `b` gives the same result.
+    pub fn same_colors(&self, a: &SvgColors, b: &SvgColors) -> bool {
+        a.fill == b.fill
+            && a.stroke == b.stroke
+            && (!self.uses_current_color || a.current_color == b.current_color)
+    }
+
+    /// Get the source of the SVG painted with the given colors.
+    pub fn apply(&self, colors: &SvgColors) -> String {
+        let mut output = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Source(source) => output.push_str(source),
+                Segment::Tag(tag) => tag.apply(colors, &mut output),
+                Segment::Stylesheet(stylesheet) => {
+                    output.push_str(&apply_stylesheet(stylesheet, colors))
+                }
+            }
+        }
+        output
+    }
+}
+
+impl Tag {
+    fn parse(start: &BytesStart, is_root: bool, is_empty: bool) -> quick_xml::Result<Self> {
+        let mut attributes = Vec::new();
+        for attribute in start.attributes() {
+            let attribute = attribute?;
+            attributes.push((
+                String::from_utf8_lossy(attribute.key.as_ref()).into_owned(),
+                String::from_utf8_lossy(&attribute.value).into_owned(),
+            ));
+        }
+
+        Ok(Self {
+            name: String::from_utf8_lossy(start.name().as_ref()).into_owned(),
+            attributes,
+            is_root,
+            is_empty,
+        })
+    }
+
+    fn apply(&self, colors: &SvgColors, output: &mut String) {
+        let mut attributes = self.attributes.clone();
+
+        for (paint, color) in colors.paints() {
+            let mut recolored = false;
+            for (name, value) in attributes.iter_mut() {
+                if name == paint && value.trim() != "none" {
+                    *value = to_hex(color);
+                    recolored = true;
+                }
+            }
+
+            // Shapes without a paint inherit it from the root
+            if self.is_root && paint == "fill" && !attributes.iter().any(|(name, _)| name == paint)
+            {
+                attributes.push((paint.to_string(), to_hex(color)));
+                recolored = true;
+            }
+
+            if recolored {
+                let opacity = format!("{paint}-opacity");
+                attributes.retain(|(name, _)| *name != opacity);
+                if color.a() < 255 {
+                    attributes.push((opacity, to_opacity(color)));
+                }
+            }
+        }
+
+        output.push('<');
+        output.push_str(&self.name);
+        for (name, value) in attributes {
+            let value = if name == "style" {
+                apply_declarations(&value, colors)
+            } else if PAINT_PROPERTIES.contains(&name.as_str()) {
+                replace_current_color(&value, colors.current_color)
+            } else {
+                value
+            };
+            let quote = if value.contains('"') { '\'' } else { '"' };
+            output.push_str(&format!(" {name}={quote}{value}{quote}"));
+        }
+        output.push_str(if self.is_empty { "/>" } else { ">" });
+    }
+}
+
+fn parse_segments(source: &str) -> quick_xml::Result<Vec<Segment>> {
+    let mut reader = Reader::from_str(source);
+    let mut segments = Vec::new();
+    let mut found_root = false;
+    let mut in_style = false;
+    let mut position = 0;
+
+    loop {
+        let event = reader.read_event()?;
+        let event_source = &source[position..reader.buffer_position()];
+        position = reader.buffer_position();
+
+        match event {
+            Event::Start(start) | Event::Empty(start) => {
+                let is_root = !found_root && start.local_name().as_ref() == b"svg";
+                found_root |= is_root;
+                in_style = start.local_name().as_ref() == b"style";
+                let is_empty = event_source.ends_with("/>");
+                if is_empty {
+                    in_style = false;
+                }
+                segments.push(Segment::Tag(Tag::parse(&start, is_root, is_empty)?));
+            }
+            Event::Text(_) | Event::CData(_) if in_style => {
+                segments.push(Segment::Stylesheet(event_source.to_string()));
+            }
+            Event::Eof => break,
+            event => {
+                if let Event::End(_) = event {
+                    in_style = false;
+                }
+                // Merge the sources that don't depend on the colors
+                if let Some(Segment::Source(previous)) = segments.last_mut() {
+                    previous.push_str(event_source);
+                } else {
+                    segments.push(Segment::Source(event_source.to_string()));
+                }
+            }
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Recolor the declarations inside every rule of a stylesheet.
+fn apply_stylesheet(stylesheet: &str, colors: &SvgColors) -> String {
+    stylesheet
+        .split('}')
+        .map(|rule| match rule.split_once('{') {
+            Some((selector, declarations)) => {
+                format!("{selector}{{{}", apply_declarations(declarations, colors))
+            }
+            None => rule.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("}")
+}
+
+/// Recolor the paints of some CSS declarations, e.g an inline `style` attribute,
+/// and resolve the `currentColor` of the other ones.
+fn apply_declarations(declarations: &str, colors: &SvgColors) -> String {
+    let mut declarations = declarations
+        .split(';')
+        .filter(|declaration| !declaration.trim().is_empty())
+        .map(|declaration| declaration.to_string())
+        .collect::<Vec<_>>();
+    let property = |declaration: &str| {
+        declaration
+            .split_once(':')
+            .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+    };
+
+    for (paint, color) in colors.paints() {
+        let mut recolored = false;
+        for declaration in declarations.iter_mut() {
+            if let Some((name, value)) = property(declaration) {
+                if name == paint && value != "none" {
+                    *declaration = format!("{paint}:{}", to_hex(color));
+                    recolored = true;
+                }
+            }
+        }
+
+        if recolored {
+            let opacity = format!("{paint}-opacity");
+            declarations.retain(|declaration| {
+                property(declaration)
+                    .map(|(name, _)| name != opacity)
+                    .unwrap_or(true)
+            });
+            if color.a() < 255 {
+                declarations.push(format!("{opacity}:{}", to_opacity(color)));
+            }
+        }
+    }
+
+    for declaration in declarations.iter_mut() {
+        if let Some((name, _)) = property(declaration) {
+            if PAINT_PROPERTIES.contains(&name.as_str()) {
+                *declaration = replace_current_color(declaration, colors.current_color);
+            }
+        }
+    }
+
+    declarations.join(";")
+}
+
+/// Replace every `currentColor` keyword, which is case-insensitive.
+fn replace_current_color(value: &str, color: Color) -> String {
+    const KEYWORD: &str = "currentcolor";
+
+    let lowercase = value.to_ascii_lowercase();
+    let mut output = String::with_capacity(value.len());
+    let mut rest = 0;
+    for (start, _) in lowercase.match_indices(KEYWORD) {
+        output.push_str(&value[rest..start]);
+        output.push_str(&to_hex(color));
+        rest = start + KEYWORD.len();
+    }
+    output.push_str(&value[rest..]);
+    output
+}
+
+fn to_hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b())
+}
+
+fn to_opacity(color: Color) -> String {
+    (color.a() as f32 / 255.0).to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use skia_safe::Color;
+
+    use super::{SvgColors, SvgTemplate};
+
+    const RED: &str = "#ff0000";
+    const BLUE: &str = "#0000ff";
+
+    fn colors(fill: Option<Color>, stroke: Option<Color>) -> SvgColors {
+        SvgColors {
+            fill,
+            stroke,
+            current_color: Color::BLUE,
+        }
+    }
+
+    #[test]
+    fn recolors_attributes() {
+        let template = SvgTemplate::parse(
+            r#"<svg viewBox="0 0 10 10"><path d="M0 0" fill="black" stroke="green"/><rect fill="none"/></svg>"#,
+        );
+
+        assert_eq!(
+            template.apply(&colors(Some(Color::RED), None)),
+            format!(
+                r#"<svg viewBox="0 0 10 10" fill="{RED}"><path d="M0 0" fill="{RED}" stroke="green"/><rect fill="none"/></svg>"#
+            )
+        );
+        assert_eq!(
+            template.apply(&colors(None, Some(Color::RED))),
+            format!(
+                r#"<svg viewBox="0 0 10 10"><path d="M0 0" fill="black" stroke="{RED}"/><rect fill="none"/></svg>"#
+            )
+        );
+    }
+
+    #[test]
+    fn recolors_with_opacity() {
+        let template =
+            SvgTemplate::parse(r#"<svg fill="black"><path fill="black" fill-opacity="1"/></svg>"#);
+
+        assert_eq!(
+            template.apply(&colors(Some(Color::from_argb(0, 255, 0, 0)), None)),
+            format!(
+                r#"<svg fill="{RED}" fill-opacity="0"><path fill="{RED}" fill-opacity="0"/></svg>"#
+            )
+        );
+        assert_eq!(
+            template.apply(&colors(Some(Color::RED), None)),
+            format!(r#"<svg fill="{RED}"><path fill="{RED}"/></svg>"#)
+        );
+    }
+
+    #[test]
+    fn recolors_inline_style() {
+        let template = SvgTemplate::parse(
+            r#"<svg fill="none"><path style="fill: black; stroke:none; opacity: 0.5"/></svg>"#,
+        );
+
+        assert_eq!(
+            template.apply(&colors(Some(Color::RED), Some(Color::RED))),
+            format!(
+                r#"<svg fill="none"><path style="fill:{RED}; stroke:none; opacity: 0.5"/></svg>"#
+            )
+        );
+    }
+
+    #[test]
+    fn recolors_style_elements() {
+        let template = SvgTemplate::parse(
+            "<svg fill=\"none\"><style>.a { fill: black; stroke: currentColor } .b{fill:none}</style><style><![CDATA[path{stroke:green}]]></style><path class=\"a\"/></svg>",
+        );
+
+        assert_eq!(
+            template.apply(&colors(Some(Color::RED), None)),
+            format!("<svg fill=\"none\"><style>.a {{fill:{RED}; stroke: {BLUE} }} .b{{fill:none}}</style><style><![CDATA[path{{stroke:green}}]]></style><path class=\"a\"/></svg>")
+        );
+        assert_eq!(
+            template.apply(&colors(None, Some(Color::RED))),
+            format!("<svg fill=\"none\"><style>.a {{ fill: black;stroke:{RED}}} .b{{fill:none}}</style><style><![CDATA[path{{stroke:{RED}}}]]></style><path class=\"a\"/></svg>")
+        );
+    }
+
+    #[test]
+    fn resolves_current_color() {
+        let template = SvgTemplate::parse(
+            r#"<svg><path stroke="currentColor" style="fill:currentcolor"/></svg>"#,
+        );
+
+        assert_eq!(
+            template.apply(&colors(None, None)),
+            format!(r#"<svg><path stroke="{BLUE}" style="fill:{BLUE}"/></svg>"#)
+        );
+    }
+
+    #[test]
+    fn resolves_current_color_only_in_paints() {
+        let template = SvgTemplate::parse(
+            "<svg><style>#currentColorIcon { stop-color: currentColor }</style><g id=\"currentColorIcon\" fill=\"currentColor\"/><use href=\"#currentcolor\" style=\"color: currentColor\"/></svg>",
+        );
+
+        assert_eq!(
+            template.apply(&colors(None, None)),
+            format!("<svg><style>#currentColorIcon {{ stop-color: {BLUE} }}</style><g id=\"currentColorIcon\" fill=\"{BLUE}\"/><use href=\"#currentcolor\" style=\"color: {BLUE}\"/></svg>")
+        );
+    }
+
+    #[test]
+    fn keeps_the_rest_of_the_source() {
+        let source = "<?xml version=\"1.0\"?>\n<!-- Icon -->\n<svg xmlns=\"http://www.w3.org/2000/svg\">\n  <text x='1'>A &amp; B</text>\n</svg>\n";
+        let template = SvgTemplate::parse(source);
+
+        assert_eq!(
+            template.apply(&colors(None, None)),
+            source.replace('\'', "\"")
+        );
+    }
+
+    #[test]
+    fn compares_colors_that_change_the_result() {
+        let with_current_color = SvgTemplate::parse(r#"<svg fill="currentColor"/>"#);
+        let without_current_color = SvgTemplate::parse(r#"<svg fill="black"/>"#);
+
+        let blue = colors(None, None);
+        let red = SvgColors {
+            current_color: Color::RED,
+            ..blue
+        };
+
+        assert!(!with_current_color.same_colors(&blue, &red));
+        assert!(without_current_color.same_colors(&blue, &red));
+        assert!(!without_current_color.same_colors(&blue, &colors(Some(Color::RED), None)));
+    }
+}
@@ -5,7 +5,7 @@ use freya_elements::events::keyboard::{
 };
 use torin::geometry::CursorPoint;
 use winit::event::{
-    ElementState, Event, KeyboardInput, ModifiersState, MouseScrollDelta, StartCause, Touch,
+    ElementState, Event, Ime, KeyboardInput, ModifiersState, MouseScrollDelta, StartCause, Touch,
     TouchPhase, WindowEvent,
 };
 use winit::event_loop::{ControlFlow, EventLoop, EventLoopProxy};
@@ -25,6 +25,7 @@ pub fn run_event_loop<State: Clone>(
     let mut last_keydown = Key::Unidentified;
     let mut last_code = Code::Unidentified;
     let mut modifiers_state = ModifiersState::empty();
+    let mut is_composing = false;
 
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;
@@ -44,6 +45,9 @@ pub fn run_event_loop<State: Clone>(
             Event::UserEvent(EventMessage::SetCursorIcon(icon)) => {
                 app.window_env().window.set_cursor_icon(icon)
             }
+            Event::UserEvent(EventMessage::FocusNode(focus_id)) => {
+                app.focus_node(focus_id);
+            }
             Event::UserEvent(ev) => {
                 if let EventMessage::UpdateTemplate(template) = ev {
                     app.vdom_replace_template(template);
@@ -65,7 +69,7 @@ pub fn run_event_loop<State: Clone>(
                     WindowEvent::MouseInput { state, button, .. } => {
                         let event_name = match state {
                             ElementState::Pressed => "mousedown",
-                            ElementState::Released => "click",
+                            ElementState::Released => "mouseup",
                         };
 
                         app.push_event(FreyaEvent::Mouse {
@@ -100,8 +104,11 @@ pub fn run_event_loop<State: Clone>(
                         modifiers_state = modifiers;
                     }
                     WindowEvent::ReceivedCharacter(a) => {
-                        // Emit the received character if the last pressed key wasn't text
-                        if last_keydown == Key::Unidentified || !modifiers_state.is_empty() {
+                        // Emit the received character if the last pressed key wasn't text,
+                        // unless it's part of an input method composition
+                        if !is_composing
+                            && (last_keydown == Key::Unidentified || !modifiers_state.is_empty())
+                        {
                             app.push_event(FreyaEvent::Keyboard {
                                 name: "keydown".to_string(),
                                 key: Key::Character(a.to_string()),
@@ -112,6 +119,51 @@ pub fn run_event_loop<State: Clone>(
                             app.process_events();
                         }
                     }
+                    WindowEvent::Ime(ime) => {
+                        let composition =
+                            |name: &str, data: &str, cursor| FreyaEvent::Composition {
+                                name: name.to_string(),
+                                data: data.to_string(),
+                                cursor,
+                            };
+
+                        match ime {
+                            Ime::Preedit(text, cursor) => {
+                                if !text.is_empty() {
+                                    if !is_composing {
+                                        is_composing = true;
+                                        app.push_event(composition("compositionstart", "", None));
+                                        // Events of the same batch have no order
+                                        app.process_events();
+                                    }
+                                    app.push_event(composition("compositionupdate", &text, cursor));
+                                } else if is_composing {
+                                    // The composition was cleared, or it's about to be committed
+                                    is_composing = false;
+                                    app.push_event(composition("compositionend", "", None));
+                                }
+                            }
+                            Ime::Commit(text) => {
+                                if is_composing {
+                                    is_composing = false;
+                                    app.push_event(composition("compositionend", &text, None));
+                                }
+                                app.push_event(FreyaEvent::TextInput {
+                                    name: "textinput".to_string(),
+                                    text,
+                                });
+                            }
+                            Ime::Disabled => {
+                                if is_composing {
+                                    is_composing = false;
+                                    app.push_event(composition("compositionend", "", None));
+                                }
+                            }
+                            Ime::Enabled => {}
+                        }
+
+                        app.process_events();
+                    }
                     WindowEvent::KeyboardInput {
                         input:
                             KeyboardInput {
@@ -147,12 +199,16 @@ pub fn run_event_loop<State: Clone>(
                                 // Uncache any key
                                 last_keydown = Key::Unidentified;
                             }
-                            app.push_event(FreyaEvent::Keyboard {
-                                name: event_name.to_string(),
-                                key,
-                                code: from_winit_to_code(&virtual_keycode),
-                                modifiers: get_modifiers(modifiers_state),
-                            });
+
+                            // Keys like Enter or Backspace edit the composition of the input method instead of the text
+                            if !is_composing || state == ElementState::Released {
+                                app.push_event(FreyaEvent::Keyboard {
+                                    name: event_name.to_string(),
+                                    key,
+                                    code: from_winit_to_code(&virtual_keycode),
+                                    modifiers: get_modifiers(modifiers_state),
+                                });
+                            }
                         } else {
                             last_keydown = Key::Unidentified;
                         }
@@ -178,6 +234,19 @@ pub fn run_event_loop<State: Clone>(
 
                         app.process_events();
                     }
+                    WindowEvent::CursorLeft { .. } => {
+                        // Leave all the hovered elements
+                        app.push_event(FreyaEvent::Mouse {
+                            name: "mouseleave".to_string(),
+                            cursor: cursor_pos,
+                            button: None,
+                        });
+
+                        app.process_events();
+                    }
+                    WindowEvent::CursorEntered { .. } => {
+                        // The elements under the cursor are entered by the `CursorMoved` that follows
+                    }
                     WindowEvent::Touch(Touch {
                         location,
                         phase,
@@ -204,6 +273,35 @@ pub fn run_event_loop<State: Clone>(
 
                         app.process_events();
                     }
+                    WindowEvent::DroppedFile(file_path) => {
+                        app.push_event(FreyaEvent::File {
+                            name: "filedrop".to_string(),
+                            cursor: cursor_pos,
+                            file_path: Some(file_path),
+                        });
+
+                        app.process_events();
+                    }
+                    WindowEvent::HoveredFile(file_path) => {
+                        app.push_event(FreyaEvent::File {
+                            name: "filehover".to_string(),
+                            cursor: cursor_pos,
+                            file_path: Some(file_path),
+                        });
+
+                        app.process_events();
+                    }
+                    WindowEvent::HoveredFileCancelled => {
+                        // The cursor might not have moved while dragging the files,
+                        // so this is emitted to the elements that received the `filehover` instead
+                        app.push_event(FreyaEvent::File {
+                            name: "filehovercancel".to_string(),
+                            cursor: cursor_pos,
+                            file_path: None,
+                        });
+
+                        app.process_events();
+                    }
                     WindowEvent::Resized(size) => {
                         app.resize(size);
                     }
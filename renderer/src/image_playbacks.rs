@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use dioxus_native_core::NodeId;
+use freya_node_state::{ImagePlayback, PlaybackCommand};
+
+use crate::assets_cache::{DecodedImage, ImageFrame};
+
+/// Playback of an animated image in a certain Node.
+struct Playback {
+    /// Position when it was last paused or seeked.
+    position: Duration,
+    /// When it started playing from `position`, `None` if paused.
+    playing_since: Option<Instant>,
+}
+
+impl Playback {
+    fn position(&self, now: Instant) -> Duration {
+        match self.playing_since {
+            Some(playing_since) => self.position + now.duration_since(playing_since),
+            None => self.position,
+        }
+    }
+
+    fn play(&mut self, now: Instant, duration: Duration, image_loop: bool) {
+        if self.playing_since.is_none() {
+            // Start over once a non-looping animation has finished
+            if !image_loop && self.position >= duration {
+                self.position = Duration::ZERO;
+            }
+            self.playing_since = Some(now);
+        }
+    }
+
+    fn pause(&mut self, now: Instant) {
+        self.position = self.position(now);
+        self.playing_since = None;
+    }
+
+    fn seek(&mut self, position: Duration, now: Instant) {
+        self.position = position;
+        if self.playing_since.is_some() {
+            self.playing_since = Some(now);
+        }
+    }
+
+    /// Get the position to render now, stopping at the end of non-looping animations.
+    fn advance(&mut self, now: Instant, duration: Duration, image_loop: bool) -> Duration {
+        let position = self.position(now);
+
+        if image_loop {
+            Duration::from_nanos((position.as_nanos() % duration.as_nanos()) as u64)
+        } else if position >= duration {
+            // Stop at the last frame
+            self.pause(now);
+            self.position = duration;
+            duration
+        } else {
+            position
+        }
+    }
+}
+
+/// Keeps the playback of the animated images and when the next frame must be rendered.
+#[derive(Default)]
+pub struct ImagePlaybacks {
+    playbacks: HashMap<NodeId, Playback>,
+    next_frame: Option<Instant>,
+}
+
+impl ImagePlaybacks {
+    /// Get the frame of the image to render now in the given Node.
+    pub fn frame<'a>(
+        &mut self,
+        node_id: NodeId,
+        image: &'a DecodedImage,
+        autoplay: bool,
+        image_loop: bool,
+        control: Option<&ImagePlayback>,
+    ) -> &'a ImageFrame {
+        if !image.is_animated() {
+            return image.first_frame();
+        }
+
+        let now = Instant::now();
+        let playback = self.playbacks.entry(node_id).or_insert_with(|| Playback {
+            position: Duration::ZERO,
+            playing_since: autoplay.then_some(now),
+        });
+
+        let duration = image.duration();
+        let mut status = control.map(|control| control.0.lock().unwrap());
+
+        if let Some(status) = &mut status {
+            for command in status.commands.drain(..) {
+                match command {
+                    PlaybackCommand::Play => playback.play(now, duration, image_loop),
+                    PlaybackCommand::Pause => playback.pause(now),
+                    PlaybackCommand::Seek(position) => playback.seek(position, now),
+                }
+            }
+        }
+
+        let position = playback.advance(now, duration, image_loop);
+
+        let (frame, until_next_frame) = image.frame_at(position);
+
+        if playback.playing_since.is_some() {
+            let next_frame = now + until_next_frame;
+            if self.next_frame.map(|t| next_frame < t).unwrap_or(true) {
+                self.next_frame = Some(next_frame);
+            }
+        }
+
+        if let Some(status) = &mut status {
+            status.playing = playback.playing_since.is_some();
+            status.position = position;
+            status.duration = duration;
+        }
+
+        frame
+    }
+
+    /// Take when the next frame of any animated image must be rendered.
+    pub fn take_next_frame(&mut self) -> Option<Instant> {
+        self.next_frame.take()
+    }
+
+    /// Forget the playbacks of the Nodes that don't pass the given predicate.
+    pub fn retain_nodes(&mut self, mut predicate: impl FnMut(NodeId) -> bool) {
+        self.playbacks.retain(|node_id, _| predicate(*node_id));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DURATION: Duration = Duration::from_secs(1);
+
+    #[test]
+    fn stops_at_the_end() {
+        let start = Instant::now();
+        let mut playback = Playback {
+            position: Duration::ZERO,
+            playing_since: Some(start),
+        };
+
+        let half = start + DURATION / 2;
+        assert_eq!(playback.advance(half, DURATION, false), DURATION / 2);
+
+        let after_end = start + DURATION * 2;
+        assert_eq!(playback.advance(after_end, DURATION, false), DURATION);
+        assert!(playback.playing_since.is_none());
+    }
+
+    #[test]
+    fn plays_again_after_the_end() {
+        let start = Instant::now();
+        let mut playback = Playback {
+            position: Duration::ZERO,
+            playing_since: Some(start),
+        };
+
+        let after_end = start + DURATION * 2;
+        playback.advance(after_end, DURATION, false);
+
+        playback.play(after_end, DURATION, false);
+        assert_eq!(playback.position, Duration::ZERO);
+        assert_eq!(playback.advance(after_end, DURATION, false), Duration::ZERO);
+
+        let later = after_end + DURATION / 4;
+        assert_eq!(playback.advance(later, DURATION, false), DURATION / 4);
+        assert!(playback.playing_since.is_some());
+    }
+
+    #[test]
+    fn resumes_where_it_was_paused() {
+        let start = Instant::now();
+        let mut playback = Playback {
+            position: Duration::ZERO,
+            playing_since: Some(start),
+        };
+
+        let half = start + DURATION / 2;
+        playback.pause(half);
+        playback.play(half, DURATION, false);
+        assert_eq!(playback.advance(half, DURATION, false), DURATION / 2);
+    }
+}
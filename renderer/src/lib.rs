@@ -1,4 +1,5 @@
 use app::App;
+pub use assets_cache::{AssetsCacheStats, SharedAssetsCacheStats, DEFAULT_ASSETS_CACHE_SIZE};
 use dioxus_core::VirtualDom;
 use dioxus_native_core::NodeId;
 use event_loop::run_event_loop;
@@ -11,9 +12,13 @@ pub use window_config::WindowConfig;
 use winit::event_loop::EventLoopBuilder;
 
 mod app;
+mod assets_cache;
+mod effects;
 mod elements;
 mod event_loop;
+mod image_playbacks;
 mod renderer;
+mod transitions;
 mod window;
 mod window_config;
 mod wireframe;
@@ -27,6 +32,7 @@ pub fn run_app<T: 'static + Clone>(
     window_config: WindowConfig<T>,
     mutations_notifier: Option<Arc<Notify>>,
     hovered_node: HoveredNode,
+    assets_cache_stats: SharedAssetsCacheStats,
 ) {
     let rt = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
@@ -57,6 +63,7 @@ pub fn run_app<T: 'static + Clone>(
         &proxy,
         mutations_notifier,
         WindowEnv::from_config(window_config, &event_loop),
+        assets_cache_stats,
     );
 
     app.init_vdom();
@@ -3,15 +3,97 @@ use dioxus_native_core::prelude::ElementNode;
 use dioxus_native_core::real_dom::NodeImmutable;
 use dioxus_native_core::NodeId;
 use freya_core::prelude::*;
-use freya_dom::prelude::DioxusNode;
-use freya_node_state::Transform;
-use skia_safe::{textlayout::FontCollection, Canvas, ClipOp, Rect};
+use freya_dom::prelude::{DioxusNode, FreyaDOM};
+use freya_layout::Layers;
+use freya_node_state::{Style, Transform, TransitionProperty};
+use skia_safe::{textlayout::FontCollection, Canvas, ClipOp, RRect, Rect};
 use skia_safe::{Matrix, Point};
 use torin::geometry::Area;
 
+use crate::assets_cache::AssetsCache;
+use crate::effects::{render_backdrop_blur, start_effects_layer};
 use crate::elements::{
-    render_image, render_label, render_paragraph, render_rect_container, render_svg,
+    render_image, render_label, render_paragraph, render_rect_container, render_shape, render_svg,
 };
+use crate::image_playbacks::ImagePlaybacks;
+use crate::transitions::Transitions;
+use crate::HoveredNode;
+
+/// Render the layers of the RealDOM into the Skia canvas
+#[allow(clippy::too_many_arguments)]
+pub fn render_layers(
+    canvas: &mut Canvas,
+    layers: &Layers,
+    viewports_collection: &ViewportsCollection,
+    font_collection: &mut FontCollection,
+    hovered_node: &HoveredNode,
+    rdom: &FreyaDOM,
+    assets_cache: &mut AssetsCache,
+    image_playbacks: &mut ImagePlaybacks,
+    interaction_states: &InteractionStates,
+    transitions: &Transitions,
+) {
+    let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
+
+    process_render(
+        viewports_collection,
+        rdom,
+        font_collection,
+        layers,
+        &mut (canvas, (&mut matrices), assets_cache, image_playbacks),
+        |dom,
+         node_id,
+         area,
+         font_collection,
+         viewports_collection,
+         (canvas, matrices, assets_cache, image_playbacks)| {
+            let render_wireframe = if let Some(hovered_node) = &hovered_node {
+                hovered_node
+                    .lock()
+                    .unwrap()
+                    .map(|id| id == *node_id)
+                    .unwrap_or_default()
+            } else {
+                false
+            };
+            if let Some(dioxus_node) = dom.rdom().get(*node_id) {
+                render_skia(
+                    canvas,
+                    area,
+                    &dioxus_node,
+                    font_collection,
+                    viewports_collection,
+                    render_wireframe,
+                    matrices,
+                    assets_cache,
+                    image_playbacks,
+                    interaction_states,
+                    transitions,
+                );
+            }
+        },
+        |dom,
+         node_id,
+         group,
+         viewports_collection,
+         (canvas, matrices, _assets_cache, _image_playbacks)| {
+            match group {
+                RenderGroup::Start => {
+                    if let Some(dioxus_node) = dom.rdom().get(*node_id) {
+                        start_render_group(
+                            canvas,
+                            &dioxus_node,
+                            viewports_collection,
+                            matrices,
+                            transitions,
+                        );
+                    }
+                }
+                RenderGroup::End => end_render_group(canvas),
+            }
+        },
+    );
+}
 
 /// Render a node into the Skia canvas
 #[allow(clippy::too_many_arguments)]
@@ -23,6 +105,10 @@ pub fn render_skia(
     viewports_collection: &ViewportsCollection,
     render_wireframe: bool,
     matrices: &mut Vec<(Matrix, Vec<NodeId>)>,
+    assets_cache: &mut AssetsCache,
+    image_playbacks: &mut ImagePlaybacks,
+    interaction_states: &InteractionStates,
+    transitions: &Transitions,
 ) {
     let node_type = &*dioxus_node.node_type();
     if let NodeType::Element(ElementNode { tag, .. }) = node_type {
@@ -30,7 +116,20 @@ pub fn render_skia(
 
         let node_transform = &*dioxus_node.get::<Transform>().unwrap();
 
-        if let Some(rotate_degs) = node_transform.rotate_degs {
+        // Elements whose layout is being animated are moved and scaled from their new area
+        if let Some(animated_area) = transitions.area(&dioxus_node.id()) {
+            let matrix = animated_layout_matrix(area, &animated_area);
+
+            matrices.push((matrix, dioxus_node.child_ids()));
+
+            canvas.concat(&matrix);
+        }
+
+        let rotate_degs = transitions
+            .number(&dioxus_node.id(), TransitionProperty::Rotate)
+            .or(node_transform.rotate_degs);
+
+        if let Some(rotate_degs) = rotate_degs {
             let mut matrix = Matrix::new_identity();
             matrix.set_rotate(
                 rotate_degs,
@@ -53,30 +152,28 @@ pub fn render_skia(
             }
         }
 
-        let viewports = viewports_collection.get(&dioxus_node.id());
+        clip_viewports(canvas, dioxus_node, viewports_collection, transitions);
 
-        // Clip all elements with their corresponding viewports
-        if let Some((_, viewports)) = viewports {
-            for viewport_id in viewports {
-                let viewport = viewports_collection.get(viewport_id).unwrap().0;
-                if let Some(viewport) = viewport {
-                    canvas.clip_rect(
-                        Rect::new(
-                            viewport.min_x(),
-                            viewport.min_y(),
-                            viewport.max_x(),
-                            viewport.max_y(),
-                        ),
-                        ClipOp::Intersect,
-                        true,
-                    );
-                }
-            }
+        let node_style = &*dioxus_node.get::<Style>().unwrap();
+
+        if node_style.backdrop_blur > 0.0 {
+            let radius = transitions
+                .number(&dioxus_node.id(), TransitionProperty::Radius)
+                .unwrap_or(node_style.radius);
+            render_backdrop_blur(canvas, area, node_style, radius);
         }
 
         match tag.as_str() {
             "rect" | "container" => {
-                render_rect_container(area, dioxus_node, canvas, font_collection);
+                let interaction_state = interaction_states.get(&dioxus_node.id());
+                render_rect_container(
+                    area,
+                    dioxus_node,
+                    canvas,
+                    font_collection,
+                    interaction_state,
+                    transitions,
+                );
             }
             "label" => {
                 render_label(area, dioxus_node, canvas, font_collection);
@@ -85,10 +182,20 @@ pub fn render_skia(
                 render_paragraph(area, dioxus_node, canvas, font_collection);
             }
             "svg" => {
-                render_svg(area, dioxus_node, canvas);
+                render_svg(area, dioxus_node, canvas, assets_cache);
             }
             "image" => {
-                render_image(area, dioxus_node, canvas);
+                render_image(
+                    area,
+                    dioxus_node,
+                    canvas,
+                    assets_cache,
+                    image_playbacks,
+                    transitions,
+                );
+            }
+            "circle" | "line" | "polygon" | "path" => {
+                render_shape(area, dioxus_node, canvas);
             }
             _ => {}
         }
@@ -100,3 +207,182 @@ pub fn render_skia(
         canvas.restore();
     }
 }
+
+/// Start rendering a node with effects, it and all its descendants are drawn into
+/// a layer that is blurred and blended when the group ends.
+fn start_render_group(
+    canvas: &mut Canvas,
+    dioxus_node: &DioxusNode,
+    viewports_collection: &ViewportsCollection,
+    matrices: &[(Matrix, Vec<NodeId>)],
+    transitions: &Transitions,
+) {
+    canvas.save();
+    let matrix = canvas.local_to_device();
+
+    // Keep the layer inside the viewports of the node, as transformed by its ancestors
+    for (matrix, nodes) in matrices {
+        if nodes.contains(&dioxus_node.id()) {
+            canvas.concat(matrix);
+        }
+    }
+    clip_viewports(canvas, dioxus_node, viewports_collection, transitions);
+    canvas.set_matrix(&matrix);
+
+    let node_style = &*dioxus_node.get::<Style>().unwrap();
+    start_effects_layer(canvas, node_style);
+}
+
+/// Finish rendering a node with effects, see [`start_render_group`].
+fn end_render_group(canvas: &mut Canvas) {
+    // Effects layer
+    canvas.restore();
+    // Clip
+    canvas.restore();
+}
+
+/// Clip the canvas with the viewports of the given node.
+fn clip_viewports(
+    canvas: &mut Canvas,
+    dioxus_node: &DioxusNode,
+    viewports_collection: &ViewportsCollection,
+    transitions: &Transitions,
+) {
+    let viewports = viewports_collection.get(&dioxus_node.id());
+
+    // Clip all elements with their corresponding viewports
+    if let Some((_, viewports)) = viewports {
+        for viewport_id in viewports {
+            let viewport = viewports_collection.get(viewport_id).unwrap().0;
+            if let Some(viewport) = viewport {
+                // Viewports with rounded corners also clip their content with them
+                let radius = dioxus_node
+                    .real_dom()
+                    .get(*viewport_id)
+                    .and_then(|viewport_node| {
+                        viewport_node.get::<Style>().map(|style| {
+                            transitions
+                                .number(viewport_id, TransitionProperty::Radius)
+                                .unwrap_or(style.radius)
+                        })
+                    })
+                    .unwrap_or_default();
+                canvas.clip_rrect(
+                    RRect::new_rect_xy(
+                        Rect::new(
+                            viewport.min_x(),
+                            viewport.min_y(),
+                            viewport.max_x(),
+                            viewport.max_y(),
+                        ),
+                        radius,
+                        radius,
+                    ),
+                    ClipOp::Intersect,
+                    true,
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use dioxus::prelude::*;
+    use dioxus_core::VirtualDom;
+    use freya_core::prelude::*;
+    use freya_dom::prelude::FreyaDOM;
+    use freya_elements::elements as dioxus_elements;
+    use skia_safe::{Color, Image, Surface};
+    use torin::geometry::{Area, Size2D};
+
+    use super::render_layers;
+    use crate::assets_cache::{AssetsCache, SharedAssetsCacheStats, DEFAULT_ASSETS_CACHE_SIZE};
+    use crate::image_playbacks::ImagePlaybacks;
+    use crate::transitions::Transitions;
+
+    /// Render the given app into a 100x100 white image.
+    fn render_app(app: Component) -> Image {
+        let mut vdom = VirtualDom::new(app);
+        let mut fdom = FreyaDOM::default();
+        fdom.init_dom(vdom.rebuild(), 1.0);
+
+        let mut font_collection = create_font_collection(&[], "Fira Sans");
+        let (layers, viewports) = process_layout(
+            &fdom,
+            Area::from_size(Size2D::new(100.0, 100.0)),
+            &mut font_collection,
+            1.0,
+        );
+
+        let mut surface = Surface::new_raster_n32_premul((100, 100)).unwrap();
+        let canvas = surface.canvas();
+        canvas.clear(Color::WHITE);
+
+        render_layers(
+            canvas,
+            &layers,
+            &viewports,
+            &mut font_collection,
+            &None,
+            &fdom,
+            &mut AssetsCache::new(DEFAULT_ASSETS_CACHE_SIZE, SharedAssetsCacheStats::default()),
+            &mut ImagePlaybacks::default(),
+            &InteractionStates::default(),
+            &Transitions::default(),
+        );
+
+        surface.image_snapshot()
+    }
+
+    #[test]
+    fn blur_covers_children() {
+        fn blurred_app(cx: Scope) -> Element {
+            render!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    padding: "25",
+                    blur: "10",
+                    rect {
+                        width: "50",
+                        height: "50",
+                        background: "black",
+                    }
+                }
+            )
+        }
+
+        let image = render_app(blurred_app);
+        let pixels = image.peek_pixels().unwrap();
+
+        // The black child bleeds outside of its area
+        assert_ne!(pixels.get_color((20, 50)), Color::WHITE);
+        // And its edges are no longer solid
+        assert_ne!(pixels.get_color((26, 50)), Color::BLACK);
+    }
+
+    #[test]
+    fn unblurred_children_keep_their_edges() {
+        fn app(cx: Scope) -> Element {
+            render!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    padding: "25",
+                    rect {
+                        width: "50",
+                        height: "50",
+                        background: "black",
+                    }
+                }
+            )
+        }
+
+        let image = render_app(app);
+        let pixels = image.peek_pixels().unwrap();
+
+        assert_eq!(pixels.get_color((20, 50)), Color::WHITE);
+        assert_eq!(pixels.get_color((26, 50)), Color::BLACK);
+    }
+}
@@ -0,0 +1,492 @@
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use dioxus_native_core::real_dom::NodeImmutable;
+use dioxus_native_core::NodeId;
+use freya_core::prelude::{AnimatedTransforms, InteractionStates};
+use freya_dom::prelude::{DioxusNode, FreyaDOM, SizeOverride, SizeOverrides};
+use freya_layout::Layers;
+use freya_node_state::{
+    Easing, SizeState, Style, Transform, TransitionProperty, TransitionState, TransitionValue,
+};
+use skia_safe::Color;
+use torin::geometry::{Area, Point2D};
+
+/// Transition of a property that is running.
+struct ActiveTransition {
+    from: TransitionValue,
+    to: TransitionValue,
+    started: Instant,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl ActiveTransition {
+    fn progress(&self, now: Instant) -> f32 {
+        if self.duration.is_zero() {
+            1.0
+        } else {
+            now.duration_since(self.started).as_secs_f32() / self.duration.as_secs_f32()
+        }
+    }
+
+    fn value(&self, now: Instant) -> TransitionValue {
+        let progress = self.easing.apply(self.progress(now));
+        self.from
+            .interpolate(&self.to, progress)
+            .unwrap_or_else(|| self.to.clone())
+    }
+}
+
+#[derive(Default)]
+struct NodeTransitions {
+    /// Last known value of the transitioned properties.
+    targets: HashMap<TransitionProperty, TransitionValue>,
+    active: HashMap<TransitionProperty, ActiveTransition>,
+    /// Current origin the layout is animated relative to, see [`layout_origin`].
+    layout_origin: Point2D,
+}
+
+/// Interpolates the properties listed in the `transition` attribute of the Nodes when they change.
+#[derive(Default)]
+pub struct Transitions {
+    nodes: HashMap<NodeId, NodeTransitions>,
+    /// Nodes whose size changed since the last layout.
+    relayout_nodes: HashSet<NodeId>,
+    now: Option<Instant>,
+}
+
+impl Transitions {
+    /// Start the transitions of the properties that changed and finish the completed ones.
+    pub fn update(
+        &mut self,
+        fdom: &FreyaDOM,
+        layers: &Layers,
+        interaction_states: &InteractionStates,
+    ) {
+        let now = Instant::now();
+        self.now = Some(now);
+
+        let rdom = fdom.rdom();
+        self.nodes.retain(|node_id, _| rdom.get(*node_id).is_some());
+
+        for node_id in layers.layers.values().flatten() {
+            let node = if let Some(node) = rdom.get(*node_id) {
+                node
+            } else {
+                continue;
+            };
+            let transition_state = node.get::<TransitionState>().unwrap();
+            if transition_state.transitions.is_empty() {
+                self.nodes.remove(node_id);
+                continue;
+            }
+
+            let node_transitions = self.nodes.entry(*node_id).or_default();
+            node_transitions.layout_origin = layout_origin(fdom, &node);
+
+            for transition in &transition_state.transitions {
+                let property = transition.property;
+                let target =
+                    if let Some(target) = target_value(fdom, &node, property, interaction_states) {
+                        target
+                    } else {
+                        continue;
+                    };
+
+                // Start from where the previous transition was, if any
+                let current = node_transitions
+                    .active
+                    .get(&property)
+                    .map(|active| active.value(now))
+                    .or_else(|| node_transitions.targets.get(&property).cloned());
+
+                if let Some(current) = current {
+                    let previous_target = node_transitions.targets.get(&property);
+                    if previous_target != Some(&target) {
+                        node_transitions.active.insert(
+                            property,
+                            ActiveTransition {
+                                from: current,
+                                to: target.clone(),
+                                started: now,
+                                duration: transition.duration,
+                                easing: transition.easing,
+                            },
+                        );
+                    }
+                }
+
+                node_transitions.targets.insert(property, target);
+            }
+
+            let mut affects_layout = false;
+            node_transitions.active.retain(|property, active| {
+                affects_layout |= property.affects_layout();
+                active.progress(now) < 1.0
+            });
+
+            // Finished layout transitions are measured once more with their real size
+            if affects_layout {
+                self.relayout_nodes.insert(*node_id);
+            }
+        }
+    }
+
+    /// Take the Nodes whose layout must be measured again.
+    pub fn take_relayout_nodes(&mut self) -> Vec<NodeId> {
+        self.relayout_nodes.drain().collect()
+    }
+
+    /// Check if the layout must be measured again.
+    pub fn must_relayout(&self) -> bool {
+        !self.relayout_nodes.is_empty()
+    }
+
+    /// Check if there is any transition running.
+    pub fn is_running(&self) -> bool {
+        self.nodes
+            .values()
+            .any(|node_transitions| !node_transitions.active.is_empty())
+    }
+
+    /// Get the current value of a property that is being transitioned.
+    pub fn value(&self, node_id: &NodeId, property: TransitionProperty) -> Option<TransitionValue> {
+        let active = self.nodes.get(node_id)?.active.get(&property)?;
+        Some(active.value(self.now?))
+    }
+
+    pub fn color(&self, node_id: &NodeId, property: TransitionProperty) -> Option<Color> {
+        match self.value(node_id, property)? {
+            TransitionValue::Color(color) => Some(color),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self, node_id: &NodeId, property: TransitionProperty) -> Option<f32> {
+        match self.value(node_id, property)? {
+            TransitionValue::Number(number) => Some(number),
+            _ => None,
+        }
+    }
+
+    /// Get the area where a Node whose layout is being animated is currently displayed.
+    pub fn area(&self, node_id: &NodeId) -> Option<Area> {
+        let layout_origin = self.nodes.get(node_id)?.layout_origin;
+        match self.value(node_id, TransitionProperty::Layout)? {
+            TransitionValue::Area(area) => Some(area.translate(layout_origin.to_vector())),
+            _ => None,
+        }
+    }
+
+    /// Get the rotations, areas and radii that are being transitioned, so the pointer events hit the Nodes where they are displayed.
+    pub fn animated_transforms(&self) -> AnimatedTransforms {
+        let mut animated_transforms = AnimatedTransforms::default();
+        for node_id in self.nodes.keys() {
+            if let Some(rotate_degs) = self.number(node_id, TransitionProperty::Rotate) {
+                animated_transforms.rotations.insert(*node_id, rotate_degs);
+            }
+            if let Some(area) = self.area(node_id) {
+                animated_transforms.areas.insert(*node_id, area);
+            }
+            if let Some(radius) = self.number(node_id, TransitionProperty::Radius) {
+                animated_transforms.radii.insert(*node_id, radius);
+            }
+        }
+        animated_transforms
+    }
+
+    /// Get the sizes of the Nodes whose layout is being transitioned.
+    pub fn size_overrides(&self) -> SizeOverrides {
+        let mut size_overrides = SizeOverrides::default();
+        for node_id in self.nodes.keys() {
+            let size = |property| match self.value(node_id, property) {
+                Some(TransitionValue::Size(size)) => Some(size),
+                _ => None,
+            };
+            let size_override = SizeOverride {
+                width: size(TransitionProperty::Width),
+                height: size(TransitionProperty::Height),
+            };
+            if size_override != SizeOverride::default() {
+                size_overrides.insert(*node_id, size_override);
+            }
+        }
+        size_overrides
+    }
+}
+
+/// Get the value a property of a Node should end up having.
+fn target_value(
+    fdom: &FreyaDOM,
+    node: &DioxusNode,
+    property: TransitionProperty,
+    interaction_states: &InteractionStates,
+) -> Option<TransitionValue> {
+    let value = match property {
+        TransitionProperty::Background => {
+            let style = node.get::<Style>().unwrap();
+            let interaction_state = interaction_states.get(&node.id());
+            TransitionValue::Color(interaction_state.background(&style))
+        }
+        TransitionProperty::Radius => TransitionValue::Number(node.get::<Style>().unwrap().radius),
+        TransitionProperty::Rotate => TransitionValue::Number(
+            node.get::<Transform>()
+                .unwrap()
+                .rotate_degs
+                .unwrap_or_default(),
+        ),
+        TransitionProperty::Width => {
+            TransitionValue::Size(node.get::<SizeState>().unwrap().width.clone())
+        }
+        TransitionProperty::Height => {
+            TransitionValue::Size(node.get::<SizeState>().unwrap().height.clone())
+        }
+        TransitionProperty::Layout => {
+            let area = fdom.layout().get(node.id())?.area;
+            let layout_origin = layout_origin(fdom, node);
+            TransitionValue::Area(area.translate(-layout_origin.to_vector()))
+        }
+    };
+    Some(value)
+}
+
+/// Origin of the content of the parent of a Node, including its scroll.
+/// Layouts are animated relative to it so scrolling or moving an ancestor doesn't animate them.
+fn layout_origin(fdom: &FreyaDOM, node: &DioxusNode) -> Point2D {
+    let parent = node
+        .parent_id()
+        .and_then(|parent_id| node.real_dom().get(parent_id));
+    let parent = if let Some(parent) = parent {
+        parent
+    } else {
+        return Point2D::default();
+    };
+
+    let inner_origin = fdom
+        .layout()
+        .get(parent.id())
+        .map(|areas| areas.inner_area.origin)
+        .unwrap_or_default();
+    let size_state = parent.get::<SizeState>().unwrap();
+    inner_origin + Point2D::new(size_state.scroll_x, size_state.scroll_y).to_vector()
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+    use dioxus::prelude::*;
+    use dioxus_core::{ScopeId, VirtualDom};
+    use dioxus_native_core::real_dom::NodeImmutable;
+    use freya_core::prelude::*;
+    use freya_dom::prelude::FreyaDOM;
+    use freya_elements::elements as dioxus_elements;
+    use freya_layout::Layers;
+    use skia_safe::textlayout::FontCollection;
+    use torin::geometry::{Area, CursorPoint, Size2D};
+
+    use super::Transitions;
+
+    static SCROLL_Y: AtomicI32 = AtomicI32::new(0);
+    static SPACER: AtomicI32 = AtomicI32::new(0);
+
+    fn app(cx: Scope) -> Element {
+        let scroll_y = SCROLL_Y.load(Ordering::Relaxed);
+        let spacer = SPACER.load(Ordering::Relaxed);
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                scroll_y: "{scroll_y}",
+                rect {
+                    height: "{spacer}",
+                }
+                rect {
+                    width: "50",
+                    height: "50",
+                    animate_layout: "1s",
+                }
+            }
+        )
+    }
+
+    static TRANSFORMED: AtomicBool = AtomicBool::new(false);
+
+    fn transformed_app(cx: Scope) -> Element {
+        let transformed = TRANSFORMED.load(Ordering::Relaxed);
+        let (spacer, rotate) = if transformed { (40, 90) } else { (0, 0) };
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                rect {
+                    height: "{spacer}",
+                }
+                rect {
+                    width: "100",
+                    height: "20",
+                    rotate: "{rotate}",
+                    transition: "rotate 1s",
+                }
+                rect {
+                    width: "100",
+                    height: "20",
+                    animate_layout: "1s",
+                }
+            }
+        )
+    }
+
+    static ROUNDED: AtomicBool = AtomicBool::new(false);
+
+    fn rounded_app(cx: Scope) -> Element {
+        let radius = if ROUNDED.load(Ordering::Relaxed) {
+            50
+        } else {
+            0
+        };
+        render!(rect {
+            width: "100",
+            height: "100",
+            radius: "{radius}",
+            transition: "radius 1s",
+        })
+    }
+
+    fn update(
+        vdom: &mut VirtualDom,
+        fdom: &mut FreyaDOM,
+        font_collection: &mut FontCollection,
+    ) -> (Layers, ViewportsCollection) {
+        vdom.mark_dirty(ScopeId(0));
+        fdom.apply_mutations(vdom.render_immediate(), 1.0);
+        fdom.layout().reset();
+        process_layout(
+            fdom,
+            Area::from_size(Size2D::new(100.0, 100.0)),
+            font_collection,
+            1.0,
+        )
+    }
+
+    #[test]
+    fn animates_layout_relative_to_the_parent() {
+        let mut vdom = VirtualDom::new(app);
+        let mut fdom = FreyaDOM::default();
+        fdom.init_dom(vdom.rebuild(), 1.0);
+        let mut font_collection = create_font_collection(&[], "Fira Sans");
+        let interaction_states = InteractionStates::default();
+        let mut transitions = Transitions::default();
+
+        let (layers, _) = update(&mut vdom, &mut fdom, &mut font_collection);
+        transitions.update(&fdom, &layers, &interaction_states);
+
+        let animated_id = fdom.rdom().get(fdom.rdom().root_id()).unwrap().child_ids()[0];
+        let animated_id = fdom.rdom().get(animated_id).unwrap().child_ids()[1];
+
+        // Scrolling the parent moves the element but doesn't animate it
+        SCROLL_Y.store(-30, Ordering::Relaxed);
+        let (layers, _) = update(&mut vdom, &mut fdom, &mut font_collection);
+        transitions.update(&fdom, &layers, &interaction_states);
+
+        assert_eq!(fdom.layout().get(animated_id).unwrap().area.min_y(), -30.0);
+        assert!(!transitions.is_running());
+        assert_eq!(transitions.area(&animated_id), None);
+
+        // Moving it inside the parent does animate it, from where it was
+        SPACER.store(20, Ordering::Relaxed);
+        let (layers, _) = update(&mut vdom, &mut fdom, &mut font_collection);
+        transitions.update(&fdom, &layers, &interaction_states);
+
+        assert_eq!(fdom.layout().get(animated_id).unwrap().area.min_y(), -10.0);
+        assert!(transitions.is_running());
+        let animated_area = transitions.area(&animated_id).unwrap();
+        assert!(animated_area.min_y() >= -30.0 && animated_area.min_y() < -10.0);
+    }
+
+    #[test]
+    fn hit_tests_animated_transforms() {
+        let mut vdom = VirtualDom::new(transformed_app);
+        let mut fdom = FreyaDOM::default();
+        fdom.init_dom(vdom.rebuild(), 1.0);
+        let mut font_collection = create_font_collection(&[], "Fira Sans");
+        let interaction_states = InteractionStates::default();
+        let mut transitions = Transitions::default();
+
+        let (layers, _) = update(&mut vdom, &mut fdom, &mut font_collection);
+        transitions.update(&fdom, &layers, &interaction_states);
+
+        let container_id = fdom.rdom().get(fdom.rdom().root_id()).unwrap().child_ids()[0];
+        let children = fdom.rdom().get(container_id).unwrap().child_ids();
+        let (rotated_id, moved_id) = (children[1], children[2]);
+
+        // Rotate the first element and move both of them down
+        TRANSFORMED.store(true, Ordering::Relaxed);
+        let (layers, viewports_collection) = update(&mut vdom, &mut fdom, &mut font_collection);
+        transitions.update(&fdom, &layers, &interaction_states);
+        assert!(transitions.is_running());
+
+        let animated_transforms = transitions.animated_transforms();
+        let hit = |node_id, x, y, animated_transforms: &AnimatedTransforms| {
+            let mut nodes_matrices = NodesMatrices::new(&fdom, animated_transforms);
+            hit_test(
+                node_id,
+                CursorPoint::new(x, y),
+                &fdom,
+                &viewports_collection,
+                &mut nodes_matrices,
+            )
+        };
+
+        // The rotation has barely started, so the element is still mostly horizontal
+        assert!(hit(rotated_id, 90.0, 50.0, &animated_transforms));
+        assert!(!hit(rotated_id, 50.0, 10.0, &animated_transforms));
+        assert!(!hit(rotated_id, 90.0, 50.0, &AnimatedTransforms::default()));
+        assert!(hit(rotated_id, 50.0, 10.0, &AnimatedTransforms::default()));
+
+        // The moved element is still displayed where it was
+        assert!(hit(moved_id, 50.0, 30.0, &animated_transforms));
+        assert!(!hit(moved_id, 50.0, 70.0, &animated_transforms));
+        assert!(!hit(moved_id, 50.0, 30.0, &AnimatedTransforms::default()));
+        assert!(hit(moved_id, 50.0, 70.0, &AnimatedTransforms::default()));
+    }
+
+    #[test]
+    fn hit_tests_animated_radius() {
+        let mut vdom = VirtualDom::new(rounded_app);
+        let mut fdom = FreyaDOM::default();
+        fdom.init_dom(vdom.rebuild(), 1.0);
+        let mut font_collection = create_font_collection(&[], "Fira Sans");
+        let interaction_states = InteractionStates::default();
+        let mut transitions = Transitions::default();
+
+        let (layers, _) = update(&mut vdom, &mut fdom, &mut font_collection);
+        transitions.update(&fdom, &layers, &interaction_states);
+
+        let rounded_id = fdom.rdom().get(fdom.rdom().root_id()).unwrap().child_ids()[0];
+
+        // Round the corners of the element
+        ROUNDED.store(true, Ordering::Relaxed);
+        let (layers, viewports_collection) = update(&mut vdom, &mut fdom, &mut font_collection);
+        transitions.update(&fdom, &layers, &interaction_states);
+        assert!(transitions.is_running());
+
+        let animated_transforms = transitions.animated_transforms();
+        let hit = |x, y, animated_transforms: &AnimatedTransforms| {
+            let mut nodes_matrices = NodesMatrices::new(&fdom, animated_transforms);
+            hit_test(
+                rounded_id,
+                CursorPoint::new(x, y),
+                &fdom,
+                &viewports_collection,
+                &mut nodes_matrices,
+            )
+        };
+
+        // The radius has barely started growing, so the corners are still hit
+        assert!(hit(2.0, 2.0, &animated_transforms));
+        assert!(!hit(2.0, 2.0, &AnimatedTransforms::default()));
+        assert!(hit(50.0, 50.0, &animated_transforms));
+    }
+}
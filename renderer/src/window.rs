@@ -1,4 +1,3 @@
-use dioxus_native_core::NodeId;
 use freya_common::EventMessage;
 use freya_core::prelude::*;
 use freya_dom::prelude::FreyaDOM;
@@ -31,10 +30,13 @@ use winit::{
 use skia_safe::{
     gpu::{gl::FramebufferInfo, BackendRenderTarget, SurfaceOrigin},
     textlayout::FontCollection,
-    ColorType, Matrix, Surface,
+    ColorType, Surface,
 };
 
-use crate::renderer::render_skia;
+use crate::assets_cache::AssetsCache;
+use crate::image_playbacks::ImagePlaybacks;
+use crate::renderer::render_layers;
+use crate::transitions::Transitions;
 use crate::window_config::WindowConfig;
 use crate::HoveredNode;
 
@@ -206,6 +208,7 @@ impl<T: Clone> WindowEnv<T> {
     }
 
     /// Render the RealDOM to Window
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &mut self,
         layers: &Layers,
@@ -213,41 +216,26 @@ impl<T: Clone> WindowEnv<T> {
         font_collection: &mut FontCollection,
         hovered_node: &HoveredNode,
         rdom: &FreyaDOM,
+        assets_cache: &mut AssetsCache,
+        image_playbacks: &mut ImagePlaybacks,
+        interaction_states: &InteractionStates,
+        transitions: &Transitions,
     ) {
         let canvas = self.surface.canvas();
 
         canvas.clear(self.window_config.background);
 
-        let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
-
-        process_render(
+        render_layers(
+            canvas,
+            layers,
             viewports_collection,
-            rdom,
             font_collection,
-            layers,
-            &mut (canvas, (&mut matrices)),
-            |dom, node_id, area, font_collection, viewports_collection, (canvas, matrices)| {
-                let render_wireframe = if let Some(hovered_node) = &hovered_node {
-                    hovered_node
-                        .lock()
-                        .unwrap()
-                        .map(|id| id == *node_id)
-                        .unwrap_or_default()
-                } else {
-                    false
-                };
-                if let Some(dioxus_node) = dom.rdom().get(*node_id) {
-                    render_skia(
-                        canvas,
-                        area,
-                        &dioxus_node,
-                        font_collection,
-                        viewports_collection,
-                        render_wireframe,
-                        matrices,
-                    );
-                }
-            },
+            hovered_node,
+            rdom,
+            assets_cache,
+            image_playbacks,
+            interaction_states,
+            transitions,
         );
 
         self.gr_context.flush_and_submit();
@@ -1,6 +1,12 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use freya_core::prelude::{CustomFont, DEFAULT_DOUBLE_CLICK_INTERVAL};
 use freya_node_state::parse_color;
 use skia_safe::Color;
 
+use crate::DEFAULT_ASSETS_CACHE_SIZE;
+
 /// Configuration for a Window.
 #[derive(Clone)]
 pub struct WindowConfig<T: Clone> {
@@ -18,6 +24,14 @@ pub struct WindowConfig<T: Clone> {
     pub state: Option<T>,
     /// Background color of the Window.
     pub background: Color,
+    /// Custom fonts to register when launching the app.
+    pub fonts: Vec<CustomFont>,
+    /// Font family used when a text doesn't specify any.
+    pub default_font: &'static str,
+    /// Max size in bytes of the decoded images and SVGs kept in memory.
+    pub assets_cache_size: usize,
+    /// Max time between two clicks for them to be a double click.
+    pub double_click_interval: Duration,
 }
 
 impl<T: Clone> Default for WindowConfig<T> {
@@ -30,6 +44,10 @@ impl<T: Clone> Default for WindowConfig<T> {
             transparent: false,
             state: None,
             background: Color::WHITE,
+            fonts: Vec::new(),
+            default_font: "Fira Sans",
+            assets_cache_size: DEFAULT_ASSETS_CACHE_SIZE,
+            double_click_interval: DEFAULT_DOUBLE_CLICK_INTERVAL,
         }
     }
 }
@@ -50,6 +68,10 @@ pub struct WindowConfigBuilder<T> {
     pub transparent: bool,
     pub state: Option<T>,
     pub background: Color,
+    pub fonts: Vec<CustomFont>,
+    pub default_font: &'static str,
+    pub assets_cache_size: usize,
+    pub double_click_interval: Duration,
 }
 
 impl<T> Default for WindowConfigBuilder<T> {
@@ -62,6 +84,10 @@ impl<T> Default for WindowConfigBuilder<T> {
             transparent: false,
             state: None,
             background: Color::WHITE,
+            fonts: Vec::new(),
+            default_font: "Fira Sans",
+            assets_cache_size: DEFAULT_ASSETS_CACHE_SIZE,
+            double_click_interval: DEFAULT_DOUBLE_CLICK_INTERVAL,
         }
     }
 }
@@ -109,6 +135,36 @@ impl<T: Clone> WindowConfigBuilder<T> {
         self
     }
 
+    /// Register a font from its bytes under the given family name.
+    pub fn with_font(mut self, family: &str, bytes: &'static [u8]) -> Self {
+        self.fonts.push(CustomFont::from_bytes(family, bytes));
+        self
+    }
+
+    /// Register a font file under the given family name, the file will be read when launching.
+    pub fn with_font_file(mut self, family: &str, path: impl Into<PathBuf>) -> Self {
+        self.fonts.push(CustomFont::from_file(family, path));
+        self
+    }
+
+    /// Specify the font family used by default.
+    pub fn with_default_font(mut self, default_font: &'static str) -> Self {
+        self.default_font = default_font;
+        self
+    }
+
+    /// Specify the max size in bytes of the decoded images and SVGs kept in memory.
+    pub fn with_assets_cache_size(mut self, assets_cache_size: usize) -> Self {
+        self.assets_cache_size = assets_cache_size;
+        self
+    }
+
+    /// Specify the max time between two clicks for them to be a double click.
+    pub fn with_double_click_interval(mut self, double_click_interval: Duration) -> Self {
+        self.double_click_interval = double_click_interval;
+        self
+    }
+
     /// Build the Window.
     pub fn build(self) -> WindowConfig<T> {
         WindowConfig {
@@ -119,6 +175,10 @@ impl<T: Clone> WindowConfigBuilder<T> {
             transparent: self.transparent,
             state: self.state,
             background: self.background,
+            fonts: self.fonts,
+            default_font: self.default_font,
+            assets_cache_size: self.assets_cache_size,
+            double_click_interval: self.double_click_interval,
         }
     }
 }
@@ -7,7 +7,7 @@ use dioxus_native_core::{
 use dioxus_native_core_macro::partial_derive_state;
 use skia_safe::Color;
 
-use crate::{parse_color, CustomAttributeValues};
+use crate::{parse_color, AttributeDiagnostics, CustomAttributeValues};
 
 #[derive(Clone, Debug, PartialEq, Eq, Component)]
 pub struct CursorSettings {
@@ -19,6 +19,16 @@ pub struct CursorSettings {
     pub highlight_color: Color,
 }
 
+/// Attributes handled by [`CursorSettings`].
+const ATTRIBUTES: &[&str] = &[
+    "cursor_index",
+    "cursor_color",
+    "cursor_mode",
+    "cursor_id",
+    "highlights",
+    "highlight_color",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for CursorSettings {
     type ParentDependencies = (Self,);
@@ -28,14 +38,7 @@ impl State<CustomAttributeValues> for CursorSettings {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
-            "cursor_index",
-            "cursor_color",
-            "cursor_mode",
-            "cursor_id",
-            "highlights",
-            "highlight_color",
-        ]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
@@ -43,18 +46,27 @@ impl State<CustomAttributeValues> for CursorSettings {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
+
         let mut cursor = parent.map(|(p,)| p.clone()).unwrap_or_default();
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
                 match attr.attribute.name.as_str() {
                     "cursor_index" => {
-                        let text = attr.value.as_text().unwrap();
-                        if text != "none" {
-                            let new_cursor_index = text.parse().unwrap();
-                            cursor.position = Some(new_cursor_index);
+                        if let Some(text) = attr.value.as_text() {
+                            if text != "none" {
+                                if let Ok(new_cursor_index) = text.parse() {
+                                    cursor.position = Some(new_cursor_index);
+                                } else {
+                                    diagnostics.invalid_value(&attr.attribute.name, attr.value);
+                                }
+                            }
                         }
                     }
                     "cursor_color" => {
@@ -62,6 +74,8 @@ impl State<CustomAttributeValues> for CursorSettings {
                             let new_cursor_color = parse_color(val);
                             if let Some(new_cursor_color) = new_cursor_color {
                                 cursor.color = new_cursor_color;
+                            } else if val != "inherit" {
+                                diagnostics.invalid_value(&attr.attribute.name, attr.value);
                             }
                         }
                     }
@@ -74,6 +88,8 @@ impl State<CustomAttributeValues> for CursorSettings {
                         if let Some(val) = attr.value.as_text() {
                             if let Ok(new_cursor_id) = val.parse() {
                                 cursor.cursor_id = Some(new_cursor_id);
+                            } else {
+                                diagnostics.invalid_value(&attr.attribute.name, attr.value);
                             }
                         }
                     }
@@ -89,6 +105,8 @@ impl State<CustomAttributeValues> for CursorSettings {
                             let new_highlight_color = parse_color(val);
                             if let Some(new_highlight_color) = new_highlight_color {
                                 cursor.highlight_color = new_highlight_color;
+                            } else if val != "inherit" {
+                                diagnostics.invalid_value(&attr.attribute.name, attr.value);
                             }
                         }
                     }
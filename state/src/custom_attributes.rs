@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use std::fmt::Display;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use bytes::Bytes;
 use dioxus_core::AttributeValue;
@@ -16,6 +17,8 @@ use tokio::sync::mpsc::UnboundedSender;
 use torin::geometry::{Area, CursorPoint};
 use uuid::Uuid;
 
+use crate::Stylesheet;
+
 /// Image Reference
 #[derive(Clone, Debug)]
 pub struct ImageReference(pub Arc<Mutex<Option<Bytes>>>);
@@ -32,6 +35,34 @@ impl Display for ImageReference {
     }
 }
 
+/// Command sent to the playback of an animated image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaybackCommand {
+    Play,
+    Pause,
+    Seek(Duration),
+}
+
+/// Playback status of an animated image, the commands are consumed by the renderer,
+/// which also keeps the rest of the fields up to date.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImagePlaybackStatus {
+    pub commands: Vec<PlaybackCommand>,
+    pub playing: bool,
+    pub position: Duration,
+    pub duration: Duration,
+}
+
+/// Image Playback
+#[derive(Clone, Debug, Default)]
+pub struct ImagePlayback(pub Arc<Mutex<ImagePlaybackStatus>>);
+
+impl PartialEq for ImagePlayback {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 /// Node Reference
 #[derive(Clone)]
 pub struct NodeReference(pub UnboundedSender<NodeReferenceLayout>);
@@ -106,6 +137,28 @@ impl Display for CursorReference {
     }
 }
 
+/// Focus Reference, binds the keyboard focus of a Node to a `use_node_focus` hook.
+#[derive(Clone)]
+pub struct FocusReference {
+    pub id: Uuid,
+    /// Notified when the Node gains (`true`) or loses (`false`) the focus.
+    pub sender: UnboundedSender<bool>,
+}
+
+impl PartialEq for FocusReference {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Debug for FocusReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FocusReference")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Group all the custom attribute types
 #[derive(Clone, PartialEq)]
 pub enum CustomAttributeValues {
@@ -115,6 +168,9 @@ pub enum CustomAttributeValues {
     ImageReference(ImageReference),
     TextHighlights(Vec<(usize, usize)>),
     Canvas(CanvasReference),
+    Stylesheet(Arc<Stylesheet>),
+    ImagePlayback(ImagePlayback),
+    FocusReference(FocusReference),
 }
 
 impl Debug for CustomAttributeValues {
@@ -126,6 +182,9 @@ impl Debug for CustomAttributeValues {
             Self::ImageReference(_) => f.debug_tuple("ImageReference").finish(),
             Self::TextHighlights(_) => f.debug_tuple("TextHighlights").finish(),
             Self::Canvas(_) => f.debug_tuple("Canvas").finish(),
+            Self::Stylesheet(_) => f.debug_tuple("Stylesheet").finish(),
+            Self::ImagePlayback(_) => f.debug_tuple("ImagePlayback").finish(),
+            Self::FocusReference(_) => f.debug_tuple("FocusReference").finish(),
         }
     }
 }
@@ -0,0 +1,217 @@
+use std::fmt::Display;
+use std::sync::{Arc, Mutex};
+
+use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::node::OwnedAttributeValue;
+use dioxus_native_core::node_ref::NodeView;
+use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State};
+use dioxus_native_core::{NodeId, SendAnyMap};
+use dioxus_native_core_macro::partial_derive_state;
+use freya_elements::elements::element_attributes;
+use fxhash::FxHashMap;
+use tracing::warn;
+
+use crate::{CustomAttributeValues, MatchedStyles};
+
+/// Why an attribute could not be applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticReason {
+    /// The element does not support this attribute.
+    UnsupportedAttribute,
+    /// The value could not be parsed.
+    InvalidValue,
+}
+
+impl Display for DiagnosticReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedAttribute => f.write_str("Unsupported attribute"),
+            Self::InvalidValue => f.write_str("Invalid value"),
+        }
+    }
+}
+
+/// A problem found while applying an attribute to a Node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributeDiagnostic {
+    pub node_id: NodeId,
+    pub attribute: String,
+    pub value: String,
+    pub reason: DiagnosticReason,
+}
+
+impl Display for AttributeDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} `{}` for attribute <{}> in Node {:?}",
+            self.reason, self.value, self.attribute, self.node_id
+        )
+    }
+}
+
+/// Collection of all the [`AttributeDiagnostic`]s in the DOM, by Node.
+///
+/// It is passed to the Node states through the update context,
+/// every state keeps the diagnostics of the attributes it handles up to date.
+#[derive(Clone, Default)]
+pub struct AttributeDiagnostics(Arc<Mutex<FxHashMap<NodeId, Vec<AttributeDiagnostic>>>>);
+
+impl AttributeDiagnostics {
+    /// Start reporting the invalid values of the given attributes for a Node,
+    /// this clears their previous diagnostics.
+    pub fn for_node(&self, node_id: NodeId, attributes: &[&str]) -> NodeDiagnostics {
+        self.clear(node_id, |diagnostic| {
+            diagnostic.reason == DiagnosticReason::InvalidValue
+                && attributes.contains(&diagnostic.attribute.as_str())
+        });
+        NodeDiagnostics {
+            diagnostics: self.clone(),
+            node_id,
+        }
+    }
+
+    /// Remove the unsupported attributes reported for a Node.
+    fn clear_unsupported(&self, node_id: NodeId) {
+        self.clear(node_id, |diagnostic| {
+            diagnostic.reason == DiagnosticReason::UnsupportedAttribute
+        });
+    }
+
+    /// Remove the diagnostics of a Node that pass the given predicate.
+    fn clear(&self, node_id: NodeId, predicate: impl Fn(&AttributeDiagnostic) -> bool) {
+        let mut diagnostics = self.0.lock().unwrap();
+        if let Some(node_diagnostics) = diagnostics.get_mut(&node_id) {
+            node_diagnostics.retain(|diagnostic| !predicate(diagnostic));
+            if node_diagnostics.is_empty() {
+                diagnostics.remove(&node_id);
+            }
+        }
+    }
+
+    /// Remove the diagnostics of the Nodes that don't pass the given predicate.
+    pub fn retain_nodes(&self, mut predicate: impl FnMut(NodeId) -> bool) {
+        self.0
+            .lock()
+            .unwrap()
+            .retain(|node_id, _| predicate(*node_id));
+    }
+
+    /// Get all the diagnostics.
+    pub fn get(&self) -> Vec<AttributeDiagnostic> {
+        self.0.lock().unwrap().values().flatten().cloned().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().unwrap().is_empty()
+    }
+
+    fn report(&self, diagnostic: AttributeDiagnostic) {
+        warn!("{diagnostic}");
+        self.0
+            .lock()
+            .unwrap()
+            .entry(diagnostic.node_id)
+            .or_default()
+            .push(diagnostic);
+    }
+}
+
+/// Reports the diagnostics of a certain Node.
+pub struct NodeDiagnostics {
+    diagnostics: AttributeDiagnostics,
+    node_id: NodeId,
+}
+
+impl NodeDiagnostics {
+    /// Report that the value of the attribute could not be parsed.
+    pub fn invalid_value(
+        &self,
+        attribute: &str,
+        value: &OwnedAttributeValue<CustomAttributeValues>,
+    ) {
+        self.report(attribute, value, DiagnosticReason::InvalidValue);
+    }
+
+    fn report(
+        &self,
+        attribute: &str,
+        value: &OwnedAttributeValue<CustomAttributeValues>,
+        reason: DiagnosticReason,
+    ) {
+        let value = match value {
+            OwnedAttributeValue::Text(text) => text.clone(),
+            OwnedAttributeValue::Custom(custom) => format!("{custom:?}"),
+            value => format!("{value:?}"),
+        };
+        self.diagnostics.report(AttributeDiagnostic {
+            node_id: self.node_id,
+            attribute: attribute.to_string(),
+            value,
+            reason,
+        })
+    }
+}
+
+/// Attributes of a Node, inline or from the stylesheet, that its element does not support.
+///
+/// The other states only see the attributes they handle, so this one sees all of them
+/// and reports the ones missing from the element's definition.
+#[derive(Default, Clone, Debug, Component)]
+pub struct UnsupportedAttributes {
+    pub attributes: Vec<String>,
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for UnsupportedAttributes {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = (MatchedStyles,);
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::All)
+        .with_tag();
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let diagnostics = context.get::<AttributeDiagnostics>().unwrap();
+        let node_id = node_view.node_id();
+
+        diagnostics.clear_unsupported(node_id);
+
+        let mut attributes = Vec::new();
+
+        if let Some(tag) = node_view.tag() {
+            let supported_attributes = element_attributes(tag).unwrap_or_default();
+            let node_diagnostics = NodeDiagnostics {
+                diagnostics: diagnostics.clone(),
+                node_id,
+            };
+
+            let inline_attributes = node_view
+                .attributes()
+                .into_iter()
+                .flatten()
+                .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+            for (name, value) in node.0.iter_all().chain(inline_attributes) {
+                if !supported_attributes.contains(&name) {
+                    node_diagnostics.report(name, value, DiagnosticReason::UnsupportedAttribute);
+                    attributes.push(name.to_string());
+                }
+            }
+        }
+
+        let changed = attributes != self.attributes;
+        *self = Self { attributes };
+        changed
+    }
+}
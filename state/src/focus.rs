@@ -0,0 +1,112 @@
+use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::node::OwnedAttributeValue;
+use dioxus_native_core::node_ref::NodeView;
+use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State};
+use dioxus_native_core::SendAnyMap;
+use dioxus_native_core_macro::partial_derive_state;
+
+use crate::{AttributeDiagnostics, CustomAttributeValues, FocusReference, MatchedStyles};
+
+#[derive(Default, Clone, Debug, Component)]
+pub struct Focus {
+    /// Whether the element can receive the keyboard focus.
+    pub focusable: bool,
+    /// Position of the element in the `Tab` navigation,
+    /// elements with a negative index can only be focused with the mouse or programmatically.
+    pub tab_index: i16,
+    pub focus_reference: Option<FocusReference>,
+}
+
+impl Focus {
+    /// Check if the element is reached when navigating with `Tab`.
+    pub fn is_tabbable(&self) -> bool {
+        self.focusable && self.tab_index >= 0
+    }
+}
+
+/// Attributes handled by [`Focus`].
+const ATTRIBUTES: &[&str] = &["focusable", "tab_index", "focus_reference"];
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for Focus {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = (MatchedStyles,);
+
+    const NODE_MASK: NodeMaskBuilder<'static> =
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES));
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
+
+        let mut focusable = None;
+        let mut tab_index = None;
+        let mut focus_reference = None;
+
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            match name {
+                "focusable" => {
+                    if let Some(attr) = value.as_text() {
+                        match attr {
+                            "true" => focusable = Some(true),
+                            "false" => focusable = Some(false),
+                            _ => diagnostics.invalid_value(name, value),
+                        }
+                    }
+                }
+                "tab_index" => {
+                    if let Some(new_tab_index) =
+                        value.as_text().and_then(|attr| attr.parse::<i16>().ok())
+                    {
+                        tab_index = Some(new_tab_index);
+                    } else {
+                        diagnostics.invalid_value(name, value);
+                    }
+                }
+                "focus_reference" => {
+                    if let OwnedAttributeValue::Custom(CustomAttributeValues::FocusReference(
+                        reference,
+                    )) = value
+                    {
+                        focus_reference = Some(reference.clone());
+                    } else {
+                        diagnostics.invalid_value(name, value);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Elements with a tab index or bound to a focus hook are focusable unless told otherwise
+        let focusable = focusable.unwrap_or(tab_index.is_some() || focus_reference.is_some());
+
+        let changed = focusable != self.focusable
+            || tab_index.unwrap_or_default() != self.tab_index
+            || focus_reference != self.focus_reference;
+        *self = Self {
+            focusable,
+            tab_index: tab_index.unwrap_or_default(),
+            focus_reference,
+        };
+        changed
+    }
+}
@@ -6,7 +6,9 @@ use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuil
 use dioxus_native_core::NodeId;
 use dioxus_native_core::SendAnyMap;
 use dioxus_native_core_macro::partial_derive_state;
-use skia_safe::textlayout::TextAlign;
+use skia_safe::textlayout::{
+    Decoration, TextAlign, TextDecoration, TextDecorationStyle, TextShadow,
+};
 use skia_safe::font_style::Weight;
 use skia_safe::font_style::Slant;
 use skia_safe::font_style::Width;
@@ -14,7 +16,7 @@ use skia_safe::Color;
 use smallvec::{smallvec, SmallVec};
 use torin::torin::Torin;
 
-use crate::{parse_color, CustomAttributeValues};
+use crate::{parse_color, AttributeDiagnostics, CustomAttributeValues, MatchedStyles};
 
 #[derive(Debug, Clone, PartialEq, Component)]
 pub struct FontStyle {
@@ -27,6 +29,11 @@ pub struct FontStyle {
     pub line_height: f32, // https://developer.mozilla.org/en-US/docs/Web/CSS/line-height,
     pub align: TextAlign,
     pub max_lines: Option<usize>,
+    pub decoration: Decoration,
+    pub letter_spacing: f32,
+    pub word_spacing: f32,
+    pub text_shadows: Vec<TextShadow>,
+    pub text_overflow: TextOverflow,
 }
 
 impl FontStyle {
@@ -44,11 +51,40 @@ impl FontStyle {
     }
 }
 
+/// How the text that doesn't fit in the available lines should be truncated.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum TextOverflow {
+    #[default]
+    Clip,
+    Ellipsis,
+    Custom(String),
+}
+
+impl TextOverflow {
+    /// Get the ellipsis that SkParagraph should place at the end of the truncated text, if any.
+    pub fn get_ellipsis(&self) -> Option<&str> {
+        match self {
+            Self::Clip => None,
+            Self::Ellipsis => Some("…"),
+            Self::Custom(custom) => Some(custom),
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Clip => "clip".to_string(),
+            Self::Ellipsis => "ellipsis".to_string(),
+            Self::Custom(custom) => format!("custom ({custom})"),
+        }
+    }
+}
+
 impl Default for FontStyle {
     fn default() -> Self {
         Self {
             color: Color::BLACK,
-            font_family: smallvec!["Fira Sans".to_string()],
+            // Empty so the default family of the FontCollection is used
+            font_family: smallvec![],
             font_size: 16.0,
             font_weight: Weight::NORMAL,
             font_slant: Slant::Upright,
@@ -56,120 +92,210 @@ impl Default for FontStyle {
             line_height: 1.2,
             align: TextAlign::default(),
             max_lines: None,
+            decoration: Decoration::default(),
+            letter_spacing: 0.0,
+            word_spacing: 0.0,
+            text_shadows: Vec::new(),
+            text_overflow: TextOverflow::default(),
         }
     }
 }
 
+/// Attributes handled by [`FontStyle`].
+const ATTRIBUTES: &[&str] = &[
+    "color",
+    "font_size",
+    "font_family",
+    "line_height",
+    "align",
+    "max_lines",
+    "font_slant",
+    "font_weight",
+    "font_width",
+    "decoration",
+    "decoration_color",
+    "decoration_style",
+    "letter_spacing",
+    "word_spacing",
+    "text_shadow",
+    "text_overflow",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for FontStyle {
     type ParentDependencies = (Self,);
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (MatchedStyles,);
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
-            "color",
-            "font_size",
-            "font_family",
-            "line_height",
-            "align",
-            "max_lines",
-            "font_slant",
-            "font_weight",
-            "font_width",
-        ]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
         let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
         let scale_factor = context.get::<f32>().unwrap();
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
 
         let mut font_style = parent
             .map(|(v,)| v.clone())
             .unwrap_or_else(|| FontStyle::default_with_scale_factor(*scale_factor));
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "color" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            let new_color = parse_color(attr);
-                            if let Some(new_color) = new_color {
-                                font_style.color = new_color;
-                            }
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            match name {
+                "color" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        let new_color = parse_color(attr);
+                        if let Some(new_color) = new_color {
+                            font_style.color = new_color;
+                        } else if attr != "inherit" {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "font_family" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            let families = attr.split(',');
-                            font_style.font_family = SmallVec::from(
-                                families
-                                    .into_iter()
-                                    .map(|f| f.trim().to_string())
-                                    .collect::<Vec<String>>(),
-                            );
-                        }
+                }
+                "font_family" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        let families = attr.split(',');
+                        font_style.font_family = SmallVec::from(
+                            families
+                                .into_iter()
+                                .map(|f| f.trim().to_string())
+                                .collect::<Vec<String>>(),
+                        );
                     }
-                    "font_size" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Ok(font_size) = attr.parse::<f32>() {
-                                font_style.font_size = font_size * scale_factor;
-                            }
+                }
+                "font_size" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Ok(font_size) = attr.parse::<f32>() {
+                            font_style.font_size = font_size * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "line_height" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Ok(line_height) = attr.parse() {
-                                font_style.line_height = line_height;
-                            }
+                }
+                "line_height" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Ok(line_height) = attr.parse() {
+                            font_style.line_height = line_height;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "align" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            font_style.align = parse_text_align(attr);
+                }
+                "align" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        font_style.align = parse_text_align(attr);
+                    }
+                }
+                "max_lines" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Ok(max_lines) = attr.parse() {
+                            font_style.max_lines = Some(max_lines);
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "max_lines" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Ok(max_lines) = attr.parse() {
-                                font_style.max_lines = Some(max_lines);
-                            }
+                }
+                "font_slant" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        font_style.font_slant = parse_font_slant(attr);
+                    }
+                }
+                "font_weight" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        font_style.font_weight = parse_font_weight(attr);
+                    }
+                }
+                "font_width" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        font_style.font_width = parse_font_width(attr);
+                    }
+                }
+                "decoration" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        font_style.decoration.ty = parse_decoration(attr);
+                    }
+                }
+                "decoration_color" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(new_decoration_color) = parse_color(attr) {
+                            font_style.decoration.color = new_decoration_color;
+                        } else if attr != "inherit" {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "font_slant" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            font_style.font_slant = parse_font_slant(attr);
+                }
+                "decoration_style" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        font_style.decoration.style = parse_decoration_style(attr);
+                    }
+                }
+                "letter_spacing" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Ok(letter_spacing) = attr.parse::<f32>() {
+                            font_style.letter_spacing = letter_spacing * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "font_weight" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            font_style.font_weight = parse_font_weight(attr);
+                }
+                "word_spacing" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Ok(word_spacing) = attr.parse::<f32>() {
+                            font_style.word_spacing = word_spacing * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "font_width" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            font_style.font_width = parse_font_width(attr);
+                }
+                "text_shadow" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(text_shadows) = parse_text_shadows(attr, *scale_factor) {
+                            font_style.text_shadows = text_shadows;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    _ => {}
                 }
+                "text_overflow" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        font_style.text_overflow = parse_text_overflow(attr);
+                    }
+                }
+                _ => {}
             }
         }
 
@@ -179,7 +305,10 @@ impl State<CustomAttributeValues> for FontStyle {
             || self.font_family != font_style.font_family
             || self.font_slant != font_style.font_slant
             || self.font_weight != font_style.font_weight
-            || self.font_width != font_style.font_width;
+            || self.font_width != font_style.font_width
+            || self.letter_spacing != font_style.letter_spacing
+            || self.word_spacing != font_style.word_spacing
+            || self.text_overflow != font_style.text_overflow;
 
         if changed_size {
             torin_layout.lock().unwrap().invalidate(node_view.node_id());
@@ -249,6 +378,14 @@ fn parse_font_width(width: &str) -> Width {
     }
 }
 
+pub fn parse_text_overflow(text_overflow: &str) -> TextOverflow {
+    match text_overflow {
+        "clip" => TextOverflow::Clip,
+        "ellipsis" => TextOverflow::Ellipsis,
+        custom => TextOverflow::Custom(custom.to_string()),
+    }
+}
+
 pub fn parse_text_align(align: &str) -> TextAlign {
     match align {
         "center" => TextAlign::Center,
@@ -260,3 +397,69 @@ pub fn parse_text_align(align: &str) -> TextAlign {
         _ => TextAlign::Left,
     }
 }
+
+pub fn parse_decoration(decoration: &str) -> TextDecoration {
+    let mut text_decoration = TextDecoration::NO_DECORATION;
+
+    for value in decoration.split_ascii_whitespace() {
+        match value {
+            "underline" => text_decoration.insert(TextDecoration::UNDERLINE),
+            "overline" => text_decoration.insert(TextDecoration::OVERLINE),
+            "line-through" => text_decoration.insert(TextDecoration::LINE_THROUGH),
+            _ => {}
+        }
+    }
+
+    text_decoration
+}
+
+pub fn parse_decoration_style(style: &str) -> TextDecorationStyle {
+    match style {
+        "solid" => TextDecorationStyle::Solid,
+        "double" => TextDecorationStyle::Double,
+        "dotted" => TextDecorationStyle::Dotted,
+        "dashed" => TextDecorationStyle::Dashed,
+        "wavy" => TextDecorationStyle::Wavy,
+        _ => TextDecorationStyle::Solid,
+    }
+}
+
+/// Parse a single text shadow with the form `<x> <y> <blur> <color>`.
+pub fn parse_text_shadow(value: &str, scale_factor: f32) -> Option<TextShadow> {
+    let mut shadow_values = value.split_ascii_whitespace();
+
+    let x = shadow_values.next()?.parse::<f32>().ok()? * scale_factor;
+    let y = shadow_values.next()?.parse::<f32>().ok()? * scale_factor;
+    let blur_sigma = shadow_values.next()?.parse::<f64>().ok()? * scale_factor as f64;
+    // The color might contain spaces, e.g: `rgb(0, 0, 0)`
+    let color = parse_color(&shadow_values.collect::<Vec<&str>>().join(" "))?;
+
+    Some(TextShadow::new(color, (x, y), blur_sigma))
+}
+
+/// Parse a list of text shadows separated by commas, e.g: `1 1 2 red, -1 -1 2 rgb(0, 0, 255)`.
+pub fn parse_text_shadows(value: &str, scale_factor: f32) -> Option<Vec<TextShadow>> {
+    if value == "none" {
+        return Some(Vec::new());
+    }
+
+    let mut shadows = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in value.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                shadows.push(parse_text_shadow(&value[start..i], scale_factor)?);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    shadows.push(parse_text_shadow(&value[start..], scale_factor)?);
+
+    Some(shadows)
+}
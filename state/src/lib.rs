@@ -2,19 +2,31 @@ use skia_safe::Color;
 
 mod cursor;
 mod custom_attributes;
+mod diagnostics;
+mod focus;
 mod font_style;
+mod pointer_events;
 mod references;
+mod shape;
 mod size;
 mod style;
+mod stylesheet;
 mod transform;
+mod transition;
 
 pub use cursor::*;
 pub use custom_attributes::*;
+pub use diagnostics::*;
+pub use focus::*;
 pub use font_style::*;
+pub use pointer_events::*;
 pub use references::*;
+pub use shape::*;
 pub use size::*;
 pub use style::*;
+pub use stylesheet::*;
 pub use transform::*;
+pub use transition::*;
 
 pub fn parse_rgb(color: &str) -> Option<Color> {
     let color = color.replace("rgb(", "").replace(')', "");
@@ -0,0 +1,87 @@
+use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::node_ref::NodeView;
+use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State};
+use dioxus_native_core::SendAnyMap;
+use dioxus_native_core_macro::partial_derive_state;
+
+use crate::{AttributeDiagnostics, CustomAttributeValues, MatchedStyles};
+
+/// Whether an element can be the target of pointer events.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointerEvents {
+    #[default]
+    Auto,
+    /// The element is transparent to the pointer, which hits the elements underneath instead.
+    None,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Component)]
+pub struct PointerEventsState {
+    pub pointer_events: PointerEvents,
+}
+
+/// Attributes handled by [`PointerEventsState`].
+const ATTRIBUTES: &[&str] = &["pointer_events"];
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for PointerEventsState {
+    type ParentDependencies = (Self,);
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = (MatchedStyles,);
+
+    const NODE_MASK: NodeMaskBuilder<'static> =
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES));
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
+
+        // Elements inherit the value of their parent unless they set their own
+        let mut pointer_events = parent
+            .map(|(parent,)| parent.pointer_events)
+            .unwrap_or_default();
+
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            if name == "pointer_events" {
+                if let Some(attr) = value.as_text() {
+                    if let Some(new_pointer_events) = parse_pointer_events(attr) {
+                        pointer_events = new_pointer_events;
+                    } else if attr != "inherit" {
+                        diagnostics.invalid_value(name, value);
+                    }
+                }
+            }
+        }
+
+        let changed = pointer_events != self.pointer_events;
+        *self = Self { pointer_events };
+        changed
+    }
+}
+
+/// Parse the `pointer_events` attribute, either `auto` or `none`.
+pub fn parse_pointer_events(value: &str) -> Option<PointerEvents> {
+    match value {
+        "auto" => Some(PointerEvents::Auto),
+        "none" => Some(PointerEvents::None),
+        _ => None,
+    }
+}
@@ -5,15 +5,27 @@ use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuil
 use dioxus_native_core::SendAnyMap;
 use dioxus_native_core_macro::partial_derive_state;
 
-use crate::{CanvasReference, CursorReference, CustomAttributeValues, ImageReference};
+use crate::{
+    AttributeDiagnostics, CanvasReference, CursorReference, CustomAttributeValues, ImagePlayback,
+    ImageReference,
+};
 
 #[derive(Default, Clone, Debug, Component)]
 pub struct References {
     pub image_ref: Option<ImageReference>,
     pub cursor_ref: Option<CursorReference>,
     pub canvas_ref: Option<CanvasReference>,
+    pub image_playback: Option<ImagePlayback>,
 }
 
+/// Attributes handled by [`References`].
+const ATTRIBUTES: &[&str] = &[
+    "cursor_reference",
+    "image_reference",
+    "canvas_reference",
+    "image_playback",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for References {
     type ParentDependencies = (Self,);
@@ -23,11 +35,7 @@ impl State<CustomAttributeValues> for References {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
-            "cursor_reference",
-            "image_reference",
-            "canvas_reference",
-        ]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
@@ -35,8 +43,13 @@ impl State<CustomAttributeValues> for References {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
+
         let mut cursor_ref = if let Some(parent) = parent {
             parent.0.cursor_ref.clone()
         } else {
@@ -44,6 +57,7 @@ impl State<CustomAttributeValues> for References {
         };
         let mut image_ref = None;
         let mut canvas_ref = None;
+        let mut image_playback = None;
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
@@ -54,6 +68,8 @@ impl State<CustomAttributeValues> for References {
                         ) = attr.value
                         {
                             cursor_ref = Some(reference.clone());
+                        } else {
+                            diagnostics.invalid_value(&attr.attribute.name, attr.value);
                         }
                     }
                     "image_reference" => {
@@ -62,6 +78,8 @@ impl State<CustomAttributeValues> for References {
                         )) = attr.value
                         {
                             image_ref = Some(reference.clone());
+                        } else {
+                            diagnostics.invalid_value(&attr.attribute.name, attr.value);
                         }
                     }
                     "canvas_reference" => {
@@ -70,22 +88,34 @@ impl State<CustomAttributeValues> for References {
                         )) = attr.value
                         {
                             canvas_ref = Some(new_canvas.clone());
+                        } else {
+                            diagnostics.invalid_value(&attr.attribute.name, attr.value);
                         }
                     }
-                    _ => {
-                        println!("Unsupported attribute <{}>", attr.attribute.name);
+                    "image_playback" => {
+                        if let OwnedAttributeValue::Custom(CustomAttributeValues::ImagePlayback(
+                            playback,
+                        )) = attr.value
+                        {
+                            image_playback = Some(playback.clone());
+                        } else {
+                            diagnostics.invalid_value(&attr.attribute.name, attr.value);
+                        }
                     }
+                    _ => {}
                 }
             }
         }
 
         let changed = cursor_ref != self.cursor_ref
             || image_ref != self.image_ref
-            || canvas_ref != self.canvas_ref;
+            || canvas_ref != self.canvas_ref
+            || image_playback != self.image_playback;
         *self = Self {
             cursor_ref,
             image_ref,
             canvas_ref,
+            image_playback,
         };
         changed
     }
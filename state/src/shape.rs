@@ -0,0 +1,310 @@
+use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::node_ref::NodeView;
+use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State};
+use dioxus_native_core::SendAnyMap;
+use dioxus_native_core_macro::partial_derive_state;
+use skia_safe::{ContourMeasureIter, Matrix, Path, Point};
+use torin::geometry::Area;
+
+use crate::{AttributeDiagnostics, CustomAttributeValues, MatchedStyles, Style, VectorColor};
+
+/// Kind of vector shape an element draws.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShapeKind {
+    /// A circle inscribed in the element.
+    Circle,
+    /// An open line going through some points, or across the element if there are none.
+    Line,
+    /// A closed shape going through some points.
+    Polygon,
+    /// A shape described with the SVG path syntax.
+    Path,
+}
+
+impl ShapeKind {
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "circle" => Some(Self::Circle),
+            "line" => Some(Self::Line),
+            "polygon" => Some(Self::Polygon),
+            "path" => Some(Self::Path),
+            _ => None,
+        }
+    }
+}
+
+/// A coordinate relative to the element's area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShapeCoordinate {
+    Pixels(f32),
+    Percentage(f32),
+}
+
+impl ShapeCoordinate {
+    pub fn resolve(&self, length: f32) -> f32 {
+        match self {
+            Self::Pixels(pixels) => *pixels,
+            Self::Percentage(percentage) => length * percentage / 100.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapePoint {
+    pub x: ShapeCoordinate,
+    pub y: ShapeCoordinate,
+}
+
+#[derive(Default, Clone, Debug, Component)]
+pub struct Shape {
+    pub kind: Option<ShapeKind>,
+    pub d: Option<String>,
+    pub points: Vec<ShapePoint>,
+    pub stroke_width: f32,
+    pub stroke_dash: Vec<f32>,
+    pub scale_factor: f32,
+}
+
+/// Attributes handled by [`Shape`].
+const ATTRIBUTES: &[&str] = &["d", "points", "stroke_width", "stroke_dash"];
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for Shape {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = (MatchedStyles,);
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES))
+        .with_tag();
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let scale_factor = context.get::<f32>().unwrap();
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
+
+        let kind = node_view.tag().and_then(ShapeKind::from_tag);
+        let mut d = None;
+        let mut points = Vec::new();
+        let mut stroke_width = *scale_factor;
+        let mut stroke_dash = Vec::new();
+
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            match name {
+                "d" => {
+                    if let Some(attr) = value.as_text() {
+                        if Path::from_svg(attr).is_some() {
+                            d = Some(attr.to_string());
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "points" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_points) = parse_points(attr, *scale_factor) {
+                            points = new_points;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "stroke_width" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Ok(new_stroke_width) = attr.parse::<f32>() {
+                            stroke_width = new_stroke_width * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "stroke_dash" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_stroke_dash) = parse_stroke_dash(attr, *scale_factor) {
+                            stroke_dash = new_stroke_dash;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let changed = (kind != self.kind)
+            || (d != self.d)
+            || (points != self.points)
+            || (stroke_width != self.stroke_width)
+            || (stroke_dash != self.stroke_dash)
+            || (*scale_factor != self.scale_factor);
+
+        *self = Self {
+            kind,
+            d,
+            points,
+            stroke_width,
+            stroke_dash,
+            scale_factor: *scale_factor,
+        };
+        changed
+    }
+}
+
+impl Shape {
+    /// Build the path of this shape inside the given area.
+    pub fn to_path(&self, area: &Area) -> Option<Path> {
+        let resolve_point = |point: &ShapePoint| {
+            Point::new(
+                area.min_x() + point.x.resolve(area.width()),
+                area.min_y() + point.y.resolve(area.height()),
+            )
+        };
+
+        match self.kind? {
+            ShapeKind::Circle => {
+                // Keep the stroke inside the element
+                let radius = (area.width().min(area.height()) - self.stroke_width) / 2.0;
+                let center = Point::new(area.center().x, area.center().y);
+                Some(Path::circle(center, radius.max(0.0), None))
+            }
+            ShapeKind::Line => {
+                let points = if self.points.is_empty() {
+                    let center = area.center();
+                    if area.width() >= area.height() {
+                        vec![
+                            Point::new(area.min_x(), center.y),
+                            Point::new(area.max_x(), center.y),
+                        ]
+                    } else {
+                        vec![
+                            Point::new(center.x, area.min_y()),
+                            Point::new(center.x, area.max_y()),
+                        ]
+                    }
+                } else {
+                    self.points.iter().map(resolve_point).collect()
+                };
+                Some(Path::polygon(&points, false, None, None))
+            }
+            ShapeKind::Polygon => {
+                let points = self.points.iter().map(resolve_point).collect::<Vec<_>>();
+                Some(Path::polygon(&points, true, None, None))
+            }
+            ShapeKind::Path => {
+                let path = Path::from_svg(self.d.as_ref()?)?;
+                let mut matrix = Matrix::translate((area.min_x(), area.min_y()));
+                matrix.pre_scale((self.scale_factor, self.scale_factor), None);
+                Some(path.with_transform(&matrix))
+            }
+        }
+    }
+
+    /// Get the fill and stroke of this shape. Shapes without any of them
+    /// are filled with the current color, except lines which are stroked instead.
+    pub fn paints(&self, style: &Style) -> (Option<VectorColor>, Option<VectorColor>) {
+        match (style.fill, style.stroke) {
+            (None, None) if self.kind == Some(ShapeKind::Line) => {
+                (None, Some(VectorColor::CurrentColor))
+            }
+            (None, None) => (Some(VectorColor::CurrentColor), None),
+            paints => paints,
+        }
+    }
+
+    /// Check if a point hits the painted parts of this shape.
+    /// Elements that are not shapes are hit anywhere inside their area.
+    pub fn hit_test(&self, area: &Area, point: (f32, f32), style: &Style) -> bool {
+        if self.kind.is_none() {
+            return true;
+        }
+
+        let (fill, stroke) = self.paints(style);
+
+        let path = if let Some(path) = self.to_path(area) {
+            path
+        } else {
+            return false;
+        };
+
+        if fill.is_some() && path.contains(point) {
+            return true;
+        }
+
+        if stroke.is_some() {
+            // Sample the outline of the shape to find the closest point to the cursor
+            let max_distance = (self.stroke_width / 2.0).max(1.0);
+            for contour in ContourMeasureIter::new(&path, false, None) {
+                let mut distance = 0.0;
+                while distance <= contour.length() {
+                    if let Some((position, _)) = contour.pos_tan(distance) {
+                        let dx = position.x - point.0;
+                        let dy = position.y - point.1;
+                        if (dx * dx + dy * dy).sqrt() <= max_distance {
+                            return true;
+                        }
+                    }
+                    distance += max_distance;
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// Parse a list of points like `0,0 100,50% 50%,100%`.
+pub fn parse_points(value: &str, scale_factor: f32) -> Option<Vec<ShapePoint>> {
+    let parse_coordinate = |coordinate: &str| {
+        if let Some(percentage) = coordinate.strip_suffix('%') {
+            Some(ShapeCoordinate::Percentage(percentage.parse().ok()?))
+        } else {
+            Some(ShapeCoordinate::Pixels(
+                coordinate.parse::<f32>().ok()? * scale_factor,
+            ))
+        }
+    };
+
+    value
+        .split_ascii_whitespace()
+        .map(|point| {
+            let (x, y) = point.split_once(',')?;
+            Some(ShapePoint {
+                x: parse_coordinate(x)?,
+                y: parse_coordinate(y)?,
+            })
+        })
+        .collect()
+}
+
+/// Parse the lengths of the dashes and gaps of a stroke, like `5 3`.
+pub fn parse_stroke_dash(value: &str, scale_factor: f32) -> Option<Vec<f32>> {
+    let dash = value
+        .split_ascii_whitespace()
+        .map(|length| Some(length.parse::<f32>().ok()? * scale_factor))
+        .collect::<Option<Vec<_>>>()?;
+
+    // Skia needs an even amount of intervals, so odd ones are repeated like in SVG
+    if dash.len() % 2 == 1 {
+        Some([dash.clone(), dash].concat())
+    } else {
+        Some(dash)
+    }
+}
@@ -10,7 +10,7 @@ use freya_common::NodeReferenceLayout;
 use tokio::sync::mpsc::UnboundedSender;
 use torin::prelude::*;
 
-use crate::CustomAttributeValues;
+use crate::{AttributeDiagnostics, CustomAttributeValues, MatchedStyles};
 
 #[derive(Default, Clone, Debug, Component)]
 pub struct SizeState {
@@ -29,42 +29,49 @@ pub struct SizeState {
     pub node_ref: Option<UnboundedSender<NodeReferenceLayout>>,
 }
 
+/// Attributes handled by [`SizeState`].
+const ATTRIBUTES: &[&str] = &[
+    "width",
+    "height",
+    "min_height",
+    "min_width",
+    "max_height",
+    "max_width",
+    "padding",
+    "direction",
+    "scroll_y",
+    "scroll_x",
+    "display",
+    "reference",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for SizeState {
     type ParentDependencies = ();
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (MatchedStyles,);
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[
-            "width",
-            "height",
-            "min_height",
-            "min_width",
-            "max_height",
-            "max_width",
-            "padding",
-            "direction",
-            "scroll_y",
-            "scroll_x",
-            "display",
-            "reference",
-        ]))
+        .with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES))
         .with_tag()
         .with_text();
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
         let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
         let scale_factor = context.get::<f32>().unwrap();
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
 
         let mut width = Size::default();
         let mut height = Size::default();
@@ -90,108 +97,133 @@ impl State<CustomAttributeValues> for SizeState {
             DirectionMode::Vertical
         };
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "width" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Some(new_width) = parse_size(attr, *scale_factor) {
-                                width = new_width;
-                            }
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            match name {
+                "width" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(new_width) = parse_size(attr, *scale_factor) {
+                            width = new_width;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "height" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Some(new_height) = parse_size(attr, *scale_factor) {
-                                height = new_height;
-                            }
+                }
+                "height" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(new_height) = parse_size(attr, *scale_factor) {
+                            height = new_height;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "min_height" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Some(new_min_height) = parse_size(attr, *scale_factor) {
-                                minimum_height = new_min_height;
-                            }
+                }
+                "min_height" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(new_min_height) = parse_size(attr, *scale_factor) {
+                            minimum_height = new_min_height;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "min_width" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Some(new_min_width) = parse_size(attr, *scale_factor) {
-                                minimum_width = new_min_width;
-                            }
+                }
+                "min_width" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(new_min_width) = parse_size(attr, *scale_factor) {
+                            minimum_width = new_min_width;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "max_height" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Some(new_max_height) = parse_size(attr, *scale_factor) {
-                                maximum_height = new_max_height;
-                            }
+                }
+                "max_height" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(new_max_height) = parse_size(attr, *scale_factor) {
+                            maximum_height = new_max_height;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "max_width" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Some(new_max_width) = parse_size(attr, *scale_factor) {
-                                maximum_width = new_max_width;
-                            }
+                }
+                "max_width" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(new_max_width) = parse_size(attr, *scale_factor) {
+                            maximum_width = new_max_width;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "padding" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            if let Some(paddings) = parse_padding(attr, *scale_factor) {
-                                padding = paddings;
-                            }
+                }
+                "padding" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Some(paddings) = parse_padding(attr, *scale_factor) {
+                            padding = paddings;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "direction" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            direction = if attr == "horizontal" {
-                                DirectionMode::Horizontal
-                            } else if attr == "both" {
-                                DirectionMode::Both
-                            } else {
-                                DirectionMode::Vertical
-                            };
-                        }
+                }
+                "direction" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        direction = if attr == "horizontal" {
+                            DirectionMode::Horizontal
+                        } else if attr == "both" {
+                            DirectionMode::Both
+                        } else {
+                            DirectionMode::Vertical
+                        };
                     }
-                    "scroll_y" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            let scroll: f32 = attr.parse().unwrap();
+                }
+                "scroll_y" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Ok(scroll) = attr.parse::<f32>() {
                             scroll_y = scroll * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "scroll_x" => {
-                        let attr = attr.value.as_text();
-                        if let Some(attr) = attr {
-                            let scroll: f32 = attr.parse().unwrap();
+                }
+                "scroll_x" => {
+                    let attr = value.as_text();
+                    if let Some(attr) = attr {
+                        if let Ok(scroll) = attr.parse::<f32>() {
                             scroll_x = scroll * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "display" => {
-                        if let Some(new_display) = attr.value.as_text() {
-                            display = parse_display(new_display)
-                        }
-                    }
-                    "reference" => {
-                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(
-                            reference,
-                        )) = attr.value
-                        {
-                            node_ref = Some(reference.0.clone());
-                        }
+                }
+                "display" => {
+                    if let Some(new_display) = value.as_text() {
+                        display = parse_display(new_display)
                     }
-                    _ => {
-                        println!("Unsupported attribute <{}>", attr.attribute.name);
+                }
+                "reference" => {
+                    if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(
+                        reference,
+                    )) = value
+                    {
+                        node_ref = Some(reference.0.clone());
+                    } else {
+                        diagnostics.invalid_value(name, value);
                     }
                 }
+                _ => {}
             }
         }
 
@@ -1,51 +1,150 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use dioxus_native_core::exports::shipyard::Component;
 use dioxus_native_core::node::OwnedAttributeValue;
 use dioxus_native_core::node_ref::NodeView;
 use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State};
 use dioxus_native_core::SendAnyMap;
 use dioxus_native_core_macro::partial_derive_state;
-use skia_safe::Color;
+use skia_safe::{BlendMode, Color};
 
-use crate::{parse_color, CustomAttributeValues};
+use crate::{parse_color, AttributeDiagnostics, CustomAttributeValues, MatchedStyles};
 
-#[derive(Default, Clone, Debug, Component)]
+#[derive(Clone, Debug, Component)]
 pub struct Style {
     pub background: Color,
     pub relative_layer: i16,
     pub shadow: ShadowSettings,
     pub radius: f32,
-    pub image_data: Option<Vec<u8>>,
-    pub svg_data: Option<Vec<u8>>,
+    pub image_data: Option<AssetData>,
+    pub svg_data: Option<AssetData>,
+    pub image_fit: ImageFit,
+    pub image_align: ImageAlign,
+    pub sampling: SamplingMode,
+    pub autoplay: bool,
+    pub image_loop: bool,
+    pub blur: f32,
+    pub backdrop_blur: f32,
+    pub blend_mode: Option<BlendMode>,
+    pub fill: Option<VectorColor>,
+    pub stroke: Option<VectorColor>,
+    pub hover_background: Option<Color>,
+    pub pressed_background: Option<Color>,
+    pub focus_border: Option<Border>,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Color::default(),
+            relative_layer: 0,
+            shadow: ShadowSettings::default(),
+            radius: 0.0,
+            image_data: None,
+            svg_data: None,
+            image_fit: ImageFit::default(),
+            image_align: ImageAlign::default(),
+            sampling: SamplingMode::default(),
+            // Animated images play and loop unless told otherwise
+            autoplay: true,
+            image_loop: true,
+            blur: 0.0,
+            backdrop_blur: 0.0,
+            blend_mode: None,
+            fill: None,
+            stroke: None,
+            hover_background: None,
+            pressed_background: None,
+            focus_border: None,
+        }
+    }
+}
+
+/// Encoded bytes of an image or SVG.
+///
+/// They are hashed once when the attribute is set, so the renderer can find the decoded asset
+/// without going through all the bytes every frame.
+#[derive(Clone, Debug)]
+pub struct AssetData {
+    bytes: Vec<u8>,
+    hash: u64,
+}
+
+impl AssetData {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        bytes.hash(&mut hasher);
+        Self {
+            bytes,
+            hash: hasher.finish(),
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Hash of the bytes.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+}
+
+impl PartialEq for AssetData {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.bytes == other.bytes
+    }
 }
 
+/// Attributes handled by [`Style`].
+const ATTRIBUTES: &[&str] = &[
+    "background",
+    "layer",
+    "shadow",
+    "radius",
+    "image_data",
+    "svg_data",
+    "svg_content",
+    "image_fit",
+    "image_align",
+    "sampling",
+    "autoplay",
+    "loop",
+    "blur",
+    "backdrop_blur",
+    "blend_mode",
+    "fill",
+    "stroke",
+    "hover_background",
+    "pressed_background",
+    "focus_border",
+];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for Style {
     type ParentDependencies = (Self,);
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (MatchedStyles,);
 
     const NODE_MASK: NodeMaskBuilder<'static> =
-        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
-            "background",
-            "layer",
-            "shadow",
-            "radius",
-            "image_data",
-            "svg_data",
-            "svg_content",
-        ]));
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES));
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
         let scale_factor = context.get::<f32>().unwrap();
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
 
         let mut background = Color::TRANSPARENT;
         let mut relative_layer = 0;
@@ -53,61 +152,204 @@ impl State<CustomAttributeValues> for Style {
         let mut radius = 0.0;
         let mut image_data = None;
         let mut svg_data = None;
+        let mut image_fit = ImageFit::default();
+        let mut image_align = ImageAlign::default();
+        let mut sampling = SamplingMode::default();
+        let mut autoplay = true;
+        let mut image_loop = true;
+        let mut blur = 0.0;
+        let mut backdrop_blur = 0.0;
+        let mut blend_mode = None;
+        let mut fill = None;
+        let mut stroke = None;
+        let mut hover_background = None;
+        let mut pressed_background = None;
+        let mut focus_border = None;
+
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "background" => {
-                        if let Some(attr) = attr.value.as_text() {
-                            let new_back = parse_color(attr);
-                            if let Some(new_back) = new_back {
-                                background = new_back;
-                            }
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            match name {
+                "background" => {
+                    if let Some(attr) = value.as_text() {
+                        let new_back = parse_color(attr);
+                        if let Some(new_back) = new_back {
+                            background = new_back;
+                        } else if attr != "inherit" {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "layer" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Ok(new_relative_layer) = attr.parse::<i16>() {
+                            relative_layer = new_relative_layer;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "layer" => {
-                        if let Some(attr) = attr.value.as_text() {
-                            if let Ok(new_relative_layer) = attr.parse::<i16>() {
-                                relative_layer = new_relative_layer;
-                            }
+                }
+                "shadow" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_shadow) = parse_shadow(attr) {
+                            shadow = new_shadow;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "shadow" => {
-                        if let Some(attr) = attr.value.as_text() {
-                            if let Some(new_shadow) = parse_shadow(attr) {
-                                shadow = new_shadow;
-                            }
+                }
+                "radius" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Ok(new_radius) = attr.parse::<f32>() {
+                            radius = new_radius * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "radius" => {
-                        if let Some(attr) = attr.value.as_text() {
-                            if let Ok(new_radius) = attr.parse::<f32>() {
-                                radius = new_radius * scale_factor;
-                            }
+                }
+                "image_data" => {
+                    if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) = value
+                    {
+                        image_data = Some(AssetData::new(bytes.clone()));
+                    } else {
+                        diagnostics.invalid_value(name, value);
+                    }
+                }
+                "svg_data" => {
+                    if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) = value
+                    {
+                        svg_data = Some(AssetData::new(bytes.clone()));
+                    } else {
+                        diagnostics.invalid_value(name, value);
+                    }
+                }
+                "svg_content" => {
+                    let text = value.as_text();
+                    svg_data = text.map(|v| AssetData::new(v.as_bytes().to_owned()));
+                }
+                "image_fit" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_image_fit) = parse_image_fit(attr) {
+                            image_fit = new_image_fit;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "image_align" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_image_align) = parse_image_align(attr) {
+                            image_align = new_image_align;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "sampling" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_sampling) = parse_sampling(attr) {
+                            sampling = new_sampling;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "autoplay" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Ok(new_autoplay) = attr.parse::<bool>() {
+                            autoplay = new_autoplay;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "loop" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Ok(new_image_loop) = attr.parse::<bool>() {
+                            image_loop = new_image_loop;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "blur" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Ok(new_blur) = attr.parse::<f32>() {
+                            blur = new_blur * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "backdrop_blur" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Ok(new_backdrop_blur) = attr.parse::<f32>() {
+                            backdrop_blur = new_backdrop_blur * scale_factor;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "blend_mode" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_blend_mode) = parse_blend_mode(attr) {
+                            blend_mode = Some(new_blend_mode);
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "fill" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_fill) = parse_vector_color(attr) {
+                            fill = Some(new_fill);
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "image_data" => {
-                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
-                            attr.value
-                        {
-                            image_data = Some(bytes.clone());
+                }
+                "stroke" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_stroke) = parse_vector_color(attr) {
+                            stroke = Some(new_stroke);
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "svg_data" => {
-                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
-                            attr.value
-                        {
-                            svg_data = Some(bytes.clone());
+                }
+                "hover_background" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_background) = parse_color(attr) {
+                            hover_background = Some(new_background);
+                        } else {
+                            diagnostics.invalid_value(name, value);
                         }
                     }
-                    "svg_content" => {
-                        let text = attr.value.as_text();
-                        svg_data = text.map(|v| v.as_bytes().to_owned());
+                }
+                "pressed_background" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_background) = parse_color(attr) {
+                            pressed_background = Some(new_background);
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
                     }
-                    _ => {
-                        println!("Unsupported attribute <{}>", attr.attribute.name);
+                }
+                "focus_border" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_border) = parse_border(attr, *scale_factor) {
+                            focus_border = Some(new_border);
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
                     }
                 }
+                _ => {}
             }
         }
 
@@ -116,7 +358,20 @@ impl State<CustomAttributeValues> for Style {
             || (shadow != self.shadow)
             || (radius != self.radius)
             || (image_data != self.image_data)
-            || (svg_data != self.svg_data);
+            || (svg_data != self.svg_data)
+            || (image_fit != self.image_fit)
+            || (image_align != self.image_align)
+            || (sampling != self.sampling)
+            || (autoplay != self.autoplay)
+            || (image_loop != self.image_loop)
+            || (blur != self.blur)
+            || (backdrop_blur != self.backdrop_blur)
+            || (blend_mode != self.blend_mode)
+            || (fill != self.fill)
+            || (stroke != self.stroke)
+            || (hover_background != self.hover_background)
+            || (pressed_background != self.pressed_background)
+            || (focus_border != self.focus_border);
 
         *self = Self {
             background,
@@ -125,11 +380,33 @@ impl State<CustomAttributeValues> for Style {
             radius,
             image_data,
             svg_data,
+            image_fit,
+            image_align,
+            sampling,
+            autoplay,
+            image_loop,
+            blur,
+            backdrop_blur,
+            blend_mode,
+            fill,
+            stroke,
+            hover_background,
+            pressed_background,
+            focus_border,
         };
         changed
     }
 }
 
+impl Style {
+    /// Check if this element is painted differently when hovered, pressed or focused.
+    pub fn has_interaction_styles(&self) -> bool {
+        self.hover_background.is_some()
+            || self.pressed_background.is_some()
+            || self.focus_border.is_some()
+    }
+}
+
 pub fn parse_shadow(value: &str) -> Option<ShadowSettings> {
     let value = value.to_string();
     let mut shadow_values = value.split_ascii_whitespace();
@@ -150,3 +427,187 @@ pub struct ShadowSettings {
     pub size: f32,
     pub color: Color,
 }
+
+/// A line drawn around an element.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Border {
+    pub width: f32,
+    pub color: Color,
+}
+
+/// Parse a border like `2 red` or `1 rgb(0, 0, 0)`.
+pub fn parse_border(value: &str, scale_factor: f32) -> Option<Border> {
+    let (width, color) = value.trim().split_once(' ')?;
+    Some(Border {
+        width: width.parse::<f32>().ok()? * scale_factor,
+        color: parse_color(color.trim())?,
+    })
+}
+
+/// How an image is sized inside its element.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Stretch the image to the element size.
+    #[default]
+    Fill,
+    /// Scale the image so it fits entirely inside the element, keeping its aspect ratio.
+    Contain,
+    /// Scale the image so it covers the whole element, keeping its aspect ratio.
+    Cover,
+    /// Keep the original size of the image.
+    None,
+    /// Like `Contain` but never bigger than the original size.
+    ScaleDown,
+}
+
+impl ImageFit {
+    /// Get the scale to apply to an image of the given size so it fits the given area.
+    /// Returns `None` for `Fill` as it doesn't keep the aspect ratio.
+    pub fn scale(&self, image_size: (f32, f32), area_size: (f32, f32)) -> Option<f32> {
+        let scale_x = area_size.0 / image_size.0;
+        let scale_y = area_size.1 / image_size.1;
+        match self {
+            Self::Fill => None,
+            Self::Contain => Some(scale_x.min(scale_y)),
+            Self::Cover => Some(scale_x.max(scale_y)),
+            Self::None => Some(1.0),
+            Self::ScaleDown => Some(scale_x.min(scale_y).min(1.0)),
+        }
+    }
+}
+
+pub fn parse_image_fit(value: &str) -> Option<ImageFit> {
+    match value {
+        "fill" => Some(ImageFit::Fill),
+        "contain" => Some(ImageFit::Contain),
+        "cover" => Some(ImageFit::Cover),
+        "none" => Some(ImageFit::None),
+        "scale-down" => Some(ImageFit::ScaleDown),
+        _ => None,
+    }
+}
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+impl Alignment {
+    /// Offset of something of the given size aligned inside the available space.
+    pub fn offset(&self, size: f32, available: f32) -> f32 {
+        match self {
+            Self::Start => 0.0,
+            Self::Center => (available - size) / 2.0,
+            Self::End => available - size,
+        }
+    }
+}
+
+/// Where an image is anchored inside its element, centered by default.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ImageAlign {
+    pub horizontal: Alignment,
+    pub vertical: Alignment,
+}
+
+/// Parse an anchor like `center`, `top`, `bottom right` or `left top`.
+pub fn parse_image_align(value: &str) -> Option<ImageAlign> {
+    let mut image_align = ImageAlign::default();
+
+    for keyword in value.split_ascii_whitespace() {
+        match keyword {
+            "left" => image_align.horizontal = Alignment::Start,
+            "right" => image_align.horizontal = Alignment::End,
+            "top" => image_align.vertical = Alignment::Start,
+            "bottom" => image_align.vertical = Alignment::End,
+            "center" => {}
+            _ => return None,
+        }
+    }
+
+    Some(image_align)
+}
+
+/// Quality used when scaling images.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SamplingMode {
+    Nearest,
+    #[default]
+    Bilinear,
+    Trilinear,
+    Mitchell,
+    CatmullRom,
+}
+
+pub fn parse_sampling(value: &str) -> Option<SamplingMode> {
+    match value {
+        "nearest" => Some(SamplingMode::Nearest),
+        "bilinear" => Some(SamplingMode::Bilinear),
+        "trilinear" => Some(SamplingMode::Trilinear),
+        "mitchell" => Some(SamplingMode::Mitchell),
+        "catmull-rom" => Some(SamplingMode::CatmullRom),
+        _ => None,
+    }
+}
+
+/// Parse a blend mode, the separable and non-separable modes use the CSS names
+/// and the Porter-Duff ones are prefixed with `src` or `dst`, e.g `src-in`.
+pub fn parse_blend_mode(value: &str) -> Option<BlendMode> {
+    match value {
+        "normal" => Some(BlendMode::SrcOver),
+        "multiply" => Some(BlendMode::Multiply),
+        "screen" => Some(BlendMode::Screen),
+        "overlay" => Some(BlendMode::Overlay),
+        "darken" => Some(BlendMode::Darken),
+        "lighten" => Some(BlendMode::Lighten),
+        "color-dodge" => Some(BlendMode::ColorDodge),
+        "color-burn" => Some(BlendMode::ColorBurn),
+        "hard-light" => Some(BlendMode::HardLight),
+        "soft-light" => Some(BlendMode::SoftLight),
+        "difference" => Some(BlendMode::Difference),
+        "exclusion" => Some(BlendMode::Exclusion),
+        "hue" => Some(BlendMode::Hue),
+        "saturation" => Some(BlendMode::Saturation),
+        "color" => Some(BlendMode::Color),
+        "luminosity" => Some(BlendMode::Luminosity),
+        "plus" => Some(BlendMode::Plus),
+        "xor" => Some(BlendMode::Xor),
+        "src" => Some(BlendMode::Src),
+        "src-in" => Some(BlendMode::SrcIn),
+        "src-out" => Some(BlendMode::SrcOut),
+        "src-atop" => Some(BlendMode::SrcATop),
+        "dst" => Some(BlendMode::Dst),
+        "dst-over" => Some(BlendMode::DstOver),
+        "dst-in" => Some(BlendMode::DstIn),
+        "dst-out" => Some(BlendMode::DstOut),
+        "dst-atop" => Some(BlendMode::DstATop),
+        _ => None,
+    }
+}
+
+/// Color used to paint vector graphics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorColor {
+    Color(Color),
+    /// The inherited text color, see [`FontStyle::color`](crate::FontStyle::color).
+    CurrentColor,
+}
+
+impl VectorColor {
+    pub fn resolve(&self, current_color: Color) -> Color {
+        match self {
+            Self::Color(color) => *color,
+            Self::CurrentColor => current_color,
+        }
+    }
+}
+
+pub fn parse_vector_color(value: &str) -> Option<VectorColor> {
+    match value {
+        "currentColor" => Some(VectorColor::CurrentColor),
+        _ => parse_color(value).map(VectorColor::Color),
+    }
+}
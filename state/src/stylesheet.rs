@@ -0,0 +1,275 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::node::OwnedAttributeValue;
+use dioxus_native_core::node_ref::NodeView;
+use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State};
+use dioxus_native_core::SendAnyMap;
+use dioxus_native_core_macro::partial_derive_state;
+
+use crate::CustomAttributeValues;
+
+/// Selects elements by their tag and/or classes, e.g: `rect`, `.primary` or `rect.primary.big`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+    pub tag: Option<String>,
+    pub classes: Vec<String>,
+}
+
+impl Selector {
+    /// How specific is this selector, rules with higher specificity override the lower ones.
+    pub fn specificity(&self) -> usize {
+        self.classes.len() * 10 + usize::from(self.tag.is_some())
+    }
+
+    /// Check if an element with the given tag and classes is matched by this selector.
+    pub fn matches(&self, tag: &str, classes: &[&str]) -> bool {
+        let tag_matches = self.tag.as_ref().map(|t| t == tag).unwrap_or(true);
+        tag_matches && self.classes.iter().all(|c| classes.contains(&c.as_str()))
+    }
+}
+
+pub fn parse_selector(selector: &str) -> Option<Selector> {
+    let selector = selector.trim();
+    let mut parts = selector.split('.');
+
+    let tag = parts.next()?;
+    let classes = parts.map(|c| c.to_string()).collect::<Vec<String>>();
+
+    if classes.iter().any(|c| c.is_empty()) || selector.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let tag = if tag.is_empty() {
+        None
+    } else {
+        Some(tag.to_string())
+    };
+
+    if tag.is_none() && classes.is_empty() {
+        return None;
+    }
+
+    Some(Selector { tag, classes })
+}
+
+/// A set of attributes applied to the elements matched by the selector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StyleRule {
+    pub selector: Selector,
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Collection of style rules, elements opt in to them with the `class` attribute.
+///
+/// A stylesheet is provided with the `stylesheet` attribute and applies to the element and all its descendants.
+/// Attributes from the rules are resolved before the inline attributes, so these always take precedence.
+///
+/// ```rust
+/// # use freya_node_state::Stylesheet;
+/// let stylesheet = Stylesheet::new()
+///     .with_rule("rect", &[("padding", "5")])
+///     .with_rule(".card", &[("background", "rgb(40, 40, 40)"), ("radius", "8")])
+///     .with_rule("label.title", &[("font_size", "22")]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Stylesheet {
+    rules: Vec<StyleRule>,
+}
+
+impl Stylesheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a new rule, invalid selectors are ignored.
+    pub fn with_rule(mut self, selector: &str, attributes: &[(&str, &str)]) -> Self {
+        if let Some(selector) = parse_selector(selector) {
+            self.rules.push(StyleRule {
+                selector,
+                attributes: attributes
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+            });
+        }
+        self
+    }
+
+    pub fn rules(&self) -> &[StyleRule] {
+        &self.rules
+    }
+
+    /// Get the attributes of all the rules matching the given tag and classes.
+    /// They are ordered by specificity, and by declaration order when they have the same specificity.
+    pub fn resolve(&self, tag: &str, classes: &[&str]) -> Vec<(&str, &str)> {
+        let mut rules = self
+            .rules
+            .iter()
+            .filter(|rule| rule.selector.matches(tag, classes))
+            .collect::<Vec<&StyleRule>>();
+
+        rules.sort_by_key(|rule| rule.selector.specificity());
+
+        rules
+            .iter()
+            .flat_map(|rule| {
+                rule.attributes
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str()))
+            })
+            .collect()
+    }
+}
+
+/// Stylesheet inherited from the closest ancestor with a `stylesheet` attribute.
+#[derive(Default, Clone, Debug, Component)]
+pub struct StylesheetState {
+    pub stylesheet: Option<Arc<Stylesheet>>,
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for StylesheetState {
+    type ParentDependencies = (Self,);
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> =
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&["stylesheet"]));
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _context: &SendAnyMap,
+    ) -> bool {
+        let mut stylesheet = parent.and_then(|(p,)| p.stylesheet.clone());
+
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                if let OwnedAttributeValue::Custom(CustomAttributeValues::Stylesheet(
+                    new_stylesheet,
+                )) = attr.value
+                {
+                    stylesheet = Some(new_stylesheet.clone());
+                }
+            }
+        }
+
+        let changed = match (&stylesheet, &self.stylesheet) {
+            (Some(a), Some(b)) => !Arc::ptr_eq(a, b),
+            (None, None) => false,
+            _ => true,
+        };
+        *self = Self { stylesheet };
+        changed
+    }
+}
+
+/// Attributes coming from the stylesheet rules that match a Node.
+///
+/// The [`Style`](crate::Style), [`FontStyle`](crate::FontStyle), [`SizeState`](crate::SizeState) and
+/// [`Transform`](crate::Transform) states depend on it, so changing the stylesheet only
+/// updates the Nodes whose matched attributes have changed.
+#[derive(Default, Clone, Component)]
+pub struct MatchedStyles {
+    attributes: Vec<(String, OwnedAttributeValue<CustomAttributeValues>)>,
+}
+
+impl MatchedStyles {
+    /// Iterate over the matched attributes that are in the given list.
+    pub fn iter<'a>(
+        &'a self,
+        supported: &'a [&'a str],
+    ) -> impl Iterator<Item = (&'a str, &'a OwnedAttributeValue<CustomAttributeValues>)> + 'a {
+        self.attributes
+            .iter()
+            .filter(|(name, _)| supported.contains(&name.as_str()))
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Iterate over all the matched attributes.
+    pub fn iter_all(
+        &self,
+    ) -> impl Iterator<Item = (&str, &OwnedAttributeValue<CustomAttributeValues>)> + '_ {
+        self.attributes
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+impl Debug for MatchedStyles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(
+                self.attributes
+                    .iter()
+                    .map(|(name, value)| (name, value.as_text())),
+            )
+            .finish()
+    }
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for MatchedStyles {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = (StylesheetState,);
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&["class"]))
+        .with_tag();
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _context: &SendAnyMap,
+    ) -> bool {
+        let mut attributes = Vec::new();
+
+        if let Some((stylesheet, tag)) = node.0.stylesheet.as_ref().zip(node_view.tag()) {
+            let mut classes = Vec::new();
+
+            if let Some(node_attributes) = node_view.attributes() {
+                for attr in node_attributes {
+                    if attr.attribute.name == "class" {
+                        if let Some(class) = attr.value.as_text() {
+                            classes.extend(class.split_ascii_whitespace());
+                        }
+                    }
+                }
+            }
+
+            attributes = stylesheet
+                .resolve(tag, &classes)
+                .into_iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        OwnedAttributeValue::Text(value.to_string()),
+                    )
+                })
+                .collect();
+        }
+
+        let changed = attributes.len() != self.attributes.len()
+            || attributes.iter().zip(self.attributes.iter()).any(
+                |((name, value), (prev_name, prev_value))| {
+                    name != prev_name || value.as_text() != prev_value.as_text()
+                },
+            );
+
+        *self = Self { attributes };
+        changed
+    }
+}
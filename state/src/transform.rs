@@ -4,48 +4,58 @@ use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuil
 use dioxus_native_core::SendAnyMap;
 use dioxus_native_core_macro::partial_derive_state;
 
-use crate::CustomAttributeValues;
+use crate::{AttributeDiagnostics, CustomAttributeValues, MatchedStyles};
 
 #[derive(Default, Clone, Debug, Component)]
 pub struct Transform {
     pub rotate_degs: Option<f32>,
 }
 
+/// Attributes handled by [`Transform`].
+const ATTRIBUTES: &[&str] = &["rotate"];
+
 #[partial_derive_state]
 impl State<CustomAttributeValues> for Transform {
     type ParentDependencies = (Self,);
 
     type ChildDependencies = ();
 
-    type NodeDependencies = ();
+    type NodeDependencies = (MatchedStyles,);
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&["rotate"]))
+        .with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES))
         .with_tag()
         .with_text();
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
-        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
+
         let mut rotate_degs = None;
 
-        if let Some(attributes) = node_view.attributes() {
-            for attr in attributes {
-                match attr.attribute.name.as_str() {
-                    "rotate" => {
-                        if let Some(attr) = attr.value.as_text() {
-                            if let Ok(degs) = attr.parse::<f32>() {
-                                rotate_degs = Some(degs)
-                            }
-                        }
-                    }
-                    _ => {
-                        println!("Unsupported attribute <{}>", attr.attribute.name);
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            if name == "rotate" {
+                if let Some(attr) = value.as_text() {
+                    if let Ok(degs) = attr.parse::<f32>() {
+                        rotate_degs = Some(degs)
+                    } else {
+                        diagnostics.invalid_value(name, value);
                     }
                 }
             }
@@ -0,0 +1,261 @@
+use std::time::Duration;
+
+use dioxus_native_core::exports::shipyard::Component;
+use dioxus_native_core::node_ref::NodeView;
+use dioxus_native_core::prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State};
+use dioxus_native_core::SendAnyMap;
+use dioxus_native_core_macro::partial_derive_state;
+use skia_safe::Color;
+use torin::prelude::{Area, Length, Point2D, Size, Size2D};
+
+use crate::{AttributeDiagnostics, CustomAttributeValues, MatchedStyles};
+
+/// Attribute that can be transitioned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionProperty {
+    Background,
+    Radius,
+    Rotate,
+    Width,
+    Height,
+    /// Position and size of the element, animated with `animate_layout`.
+    Layout,
+}
+
+impl TransitionProperty {
+    /// Check if transitioning this property changes the layout.
+    pub fn affects_layout(&self) -> bool {
+        matches!(self, Self::Width | Self::Height)
+    }
+}
+
+/// How the progress of a transition is distributed over its duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl Easing {
+    /// Map a linear progress between `0.0` and `1.0` to the eased progress.
+    pub fn apply(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => progress,
+            Self::EaseIn => progress.powi(3),
+            Self::EaseOut => 1.0 - (1.0 - progress).powi(3),
+            Self::EaseInOut => {
+                if progress < 0.5 {
+                    4.0 * progress.powi(3)
+                } else {
+                    1.0 - (-2.0 * progress + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Transition of a property, like `background 200ms ease-out`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    pub property: TransitionProperty,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+/// Value of a transitioned property.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransitionValue {
+    Color(Color),
+    Number(f32),
+    Size(Size),
+    Area(Area),
+}
+
+impl TransitionValue {
+    /// Get the value between `self` and `to` at the given progress.
+    /// Sizes can only be interpolated when both are in pixels or both are percentages.
+    pub fn interpolate(&self, to: &Self, progress: f32) -> Option<Self> {
+        let lerp = |from: f32, to: f32| from + (to - from) * progress;
+        match (self, to) {
+            (Self::Color(from), Self::Color(to)) => {
+                let channel = |from: u8, to: u8| lerp(from as f32, to as f32).round() as u8;
+                Some(Self::Color(Color::from_argb(
+                    channel(from.a(), to.a()),
+                    channel(from.r(), to.r()),
+                    channel(from.g(), to.g()),
+                    channel(from.b(), to.b()),
+                )))
+            }
+            (Self::Number(from), Self::Number(to)) => Some(Self::Number(lerp(*from, *to))),
+            (Self::Size(Size::Pixels(from)), Self::Size(Size::Pixels(to))) => Some(Self::Size(
+                Size::Pixels(Length::new(lerp(from.get(), to.get()))),
+            )),
+            (Self::Size(Size::Percentage(from)), Self::Size(Size::Percentage(to))) => Some(
+                Self::Size(Size::Percentage(Length::new(lerp(from.get(), to.get())))),
+            ),
+            (Self::Area(from), Self::Area(to)) => Some(Self::Area(Area::new(
+                Point2D::new(
+                    lerp(from.min_x(), to.min_x()),
+                    lerp(from.min_y(), to.min_y()),
+                ),
+                Size2D::new(
+                    lerp(from.width(), to.width()),
+                    lerp(from.height(), to.height()),
+                ),
+            ))),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug, Component)]
+pub struct TransitionState {
+    pub transitions: Vec<Transition>,
+}
+
+/// Attributes handled by [`TransitionState`].
+const ATTRIBUTES: &[&str] = &["transition", "animate_layout"];
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for TransitionState {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = (MatchedStyles,);
+
+    const NODE_MASK: NodeMaskBuilder<'static> =
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(ATTRIBUTES));
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let diagnostics = context
+            .get::<AttributeDiagnostics>()
+            .unwrap()
+            .for_node(node_view.node_id(), ATTRIBUTES);
+
+        let mut transitions = Vec::new();
+        let mut layout_transition = None;
+
+        let inline_attributes = node_view
+            .attributes()
+            .into_iter()
+            .flatten()
+            .map(|attr| (attr.attribute.name.as_str(), attr.value));
+
+        // Attributes from the stylesheet go first so the inline ones can override them
+        for (name, value) in node.0.iter(ATTRIBUTES).chain(inline_attributes) {
+            match name {
+                "transition" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_transitions) = parse_transitions(attr) {
+                            transitions = new_transitions;
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                "animate_layout" => {
+                    if let Some(attr) = value.as_text() {
+                        if let Some(new_layout_transition) = parse_layout_transition(attr) {
+                            layout_transition = Some(new_layout_transition);
+                        } else {
+                            diagnostics.invalid_value(name, value);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        transitions.extend(layout_transition);
+
+        let changed = transitions != self.transitions;
+        *self = Self { transitions };
+        changed
+    }
+}
+
+/// Parse a list of transitions like `background 200ms ease-out, width 0.15s`.
+pub fn parse_transitions(value: &str) -> Option<Vec<Transition>> {
+    value
+        .split(',')
+        .map(|transition| {
+            let mut parts = transition.split_ascii_whitespace();
+
+            let property = match parts.next()? {
+                "background" => TransitionProperty::Background,
+                "radius" => TransitionProperty::Radius,
+                "rotate" => TransitionProperty::Rotate,
+                "width" => TransitionProperty::Width,
+                "height" => TransitionProperty::Height,
+                _ => return None,
+            };
+
+            let duration = parse_duration(parts.next()?)?;
+            let easing = parse_easing(parts.next())?;
+
+            if parts.next().is_some() {
+                return None;
+            }
+
+            Some(Transition {
+                property,
+                duration,
+                easing,
+            })
+        })
+        .collect()
+}
+
+/// Parse the duration and easing of the layout animation, like `300ms ease-out`.
+pub fn parse_layout_transition(value: &str) -> Option<Transition> {
+    let mut parts = value.split_ascii_whitespace();
+
+    let duration = parse_duration(parts.next()?)?;
+    let easing = parse_easing(parts.next())?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(Transition {
+        property: TransitionProperty::Layout,
+        duration,
+        easing,
+    })
+}
+
+/// Parse a duration like `200ms` or `0.2s`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let seconds = if let Some(millis) = value.strip_suffix("ms") {
+        millis.parse::<f32>().ok()? / 1000.0
+    } else {
+        value.strip_suffix('s')?.parse::<f32>().ok()?
+    };
+    if !seconds.is_finite() || seconds < 0.0 {
+        return None;
+    }
+    Some(Duration::from_secs_f32(seconds))
+}
+
+/// Parse an easing, the default one is `linear`.
+fn parse_easing(value: Option<&str>) -> Option<Easing> {
+    match value {
+        None | Some("linear") => Some(Easing::Linear),
+        Some("ease-in") => Some(Easing::EaseIn),
+        Some("ease-out") => Some(Easing::EaseOut),
+        Some("ease-in-out") => Some(Easing::EaseInOut),
+        _ => None,
+    }
+}
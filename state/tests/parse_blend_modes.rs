@@ -0,0 +1,23 @@
+use freya_node_state::parse_blend_mode;
+use skia_safe::BlendMode;
+
+#[test]
+fn parse_separable_blend_modes() {
+    assert_eq!(parse_blend_mode("normal"), Some(BlendMode::SrcOver));
+    assert_eq!(parse_blend_mode("multiply"), Some(BlendMode::Multiply));
+    assert_eq!(parse_blend_mode("color-dodge"), Some(BlendMode::ColorDodge));
+    assert_eq!(parse_blend_mode("soft-light"), Some(BlendMode::SoftLight));
+}
+
+#[test]
+fn parse_porter_duff_blend_modes() {
+    assert_eq!(parse_blend_mode("src-in"), Some(BlendMode::SrcIn));
+    assert_eq!(parse_blend_mode("dst-out"), Some(BlendMode::DstOut));
+    assert_eq!(parse_blend_mode("xor"), Some(BlendMode::Xor));
+}
+
+#[test]
+fn parse_invalid_blend_mode() {
+    assert_eq!(parse_blend_mode("Multiply"), None);
+    assert_eq!(parse_blend_mode("burn"), None);
+}
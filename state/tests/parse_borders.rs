@@ -0,0 +1,30 @@
+use freya_node_state::{parse_border, Border};
+use skia_safe::Color;
+
+#[test]
+fn parse_basic_border() {
+    assert_eq!(
+        parse_border("2 red", 1.0),
+        Some(Border {
+            width: 2.0,
+            color: Color::RED
+        })
+    );
+}
+
+#[test]
+fn parse_scaled_rgb_border() {
+    assert_eq!(
+        parse_border("1.5 rgb(10, 20, 30)", 2.0),
+        Some(Border {
+            width: 3.0,
+            color: Color::from_rgb(10, 20, 30)
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_border() {
+    assert_eq!(parse_border("red", 1.0), None);
+    assert_eq!(parse_border("2 something", 1.0), None);
+}
@@ -0,0 +1,56 @@
+use freya_node_state::{
+    parse_image_align, parse_image_fit, parse_sampling, Alignment, ImageAlign, ImageFit,
+    SamplingMode,
+};
+
+#[test]
+fn parse_image_fits() {
+    assert_eq!(parse_image_fit("fill"), Some(ImageFit::Fill));
+    assert_eq!(parse_image_fit("contain"), Some(ImageFit::Contain));
+    assert_eq!(parse_image_fit("cover"), Some(ImageFit::Cover));
+    assert_eq!(parse_image_fit("none"), Some(ImageFit::None));
+    assert_eq!(parse_image_fit("scale-down"), Some(ImageFit::ScaleDown));
+    assert_eq!(parse_image_fit("stretch"), None);
+}
+
+#[test]
+fn image_fit_scales() {
+    let image = (200.0, 100.0);
+    let area = (100.0, 100.0);
+    assert_eq!(ImageFit::Fill.scale(image, area), None);
+    assert_eq!(ImageFit::Contain.scale(image, area), Some(0.5));
+    assert_eq!(ImageFit::Cover.scale(image, area), Some(1.0));
+    assert_eq!(ImageFit::None.scale(image, area), Some(1.0));
+    assert_eq!(ImageFit::ScaleDown.scale(image, area), Some(0.5));
+    assert_eq!(ImageFit::ScaleDown.scale(image, (500.0, 500.0)), Some(1.0));
+}
+
+#[test]
+fn parse_image_aligns() {
+    assert_eq!(parse_image_align("center"), Some(ImageAlign::default()));
+    assert_eq!(
+        parse_image_align("bottom right"),
+        Some(ImageAlign {
+            horizontal: Alignment::End,
+            vertical: Alignment::End
+        })
+    );
+    assert_eq!(
+        parse_image_align("left"),
+        Some(ImageAlign {
+            horizontal: Alignment::Start,
+            vertical: Alignment::Center
+        })
+    );
+    assert_eq!(parse_image_align("middle"), None);
+}
+
+#[test]
+fn parse_sampling_modes() {
+    assert_eq!(parse_sampling("nearest"), Some(SamplingMode::Nearest));
+    assert_eq!(parse_sampling("bilinear"), Some(SamplingMode::Bilinear));
+    assert_eq!(parse_sampling("trilinear"), Some(SamplingMode::Trilinear));
+    assert_eq!(parse_sampling("mitchell"), Some(SamplingMode::Mitchell));
+    assert_eq!(parse_sampling("catmull-rom"), Some(SamplingMode::CatmullRom));
+    assert_eq!(parse_sampling("best"), None);
+}
@@ -0,0 +1,19 @@
+use freya_node_state::{parse_pointer_events, PointerEvents};
+
+#[test]
+fn parse_auto_pointer_events() {
+    let pointer_events = parse_pointer_events("auto");
+    assert_eq!(pointer_events, Some(PointerEvents::Auto));
+}
+
+#[test]
+fn parse_none_pointer_events() {
+    let pointer_events = parse_pointer_events("none");
+    assert_eq!(pointer_events, Some(PointerEvents::None));
+}
+
+#[test]
+fn parse_invalid_pointer_events() {
+    let pointer_events = parse_pointer_events("freya!!");
+    assert_eq!(pointer_events, None);
+}
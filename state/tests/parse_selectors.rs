@@ -0,0 +1,66 @@
+use freya_node_state::{parse_selector, Selector, Stylesheet};
+
+#[test]
+fn parse_tag_selector() {
+    let selector = parse_selector("rect");
+    assert_eq!(
+        selector,
+        Some(Selector {
+            tag: Some("rect".to_string()),
+            classes: vec![]
+        })
+    );
+}
+
+#[test]
+fn parse_class_selector() {
+    let selector = parse_selector(".card");
+    assert_eq!(
+        selector,
+        Some(Selector {
+            tag: None,
+            classes: vec!["card".to_string()]
+        })
+    );
+}
+
+#[test]
+fn parse_tag_and_classes_selector() {
+    let selector = parse_selector("label.title.big").unwrap();
+    assert_eq!(selector.tag, Some("label".to_string()));
+    assert_eq!(selector.classes, vec!["title", "big"]);
+    assert!(selector.matches("label", &["big", "title", "other"]));
+    assert!(!selector.matches("label", &["title"]));
+    assert!(!selector.matches("rect", &["title", "big"]));
+}
+
+#[test]
+fn parse_invalid_selectors() {
+    assert_eq!(parse_selector(""), None);
+    assert_eq!(parse_selector("."), None);
+    assert_eq!(parse_selector("rect..card"), None);
+    assert_eq!(parse_selector("rect .card"), None);
+}
+
+#[test]
+fn resolve_by_specificity() {
+    let stylesheet = Stylesheet::new()
+        .with_rule("rect.card", &[("background", "red")])
+        .with_rule(".card", &[("background", "blue"), ("radius", "5")])
+        .with_rule("rect", &[("background", "green")])
+        .with_rule("label", &[("color", "white")]);
+
+    let attributes = stylesheet.resolve("rect", &["card"]);
+    assert_eq!(
+        attributes,
+        vec![
+            ("background", "green"),
+            ("background", "blue"),
+            ("radius", "5"),
+            ("background", "red")
+        ]
+    );
+
+    let attributes = stylesheet.resolve("rect", &[]);
+    assert_eq!(attributes, vec![("background", "green")]);
+}
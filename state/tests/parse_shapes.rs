@@ -0,0 +1,43 @@
+use freya_node_state::{parse_points, parse_stroke_dash, ShapeCoordinate, ShapePoint};
+
+#[test]
+fn parse_pixel_points() {
+    assert_eq!(
+        parse_points("0,0 10,20", 2.0),
+        Some(vec![
+            ShapePoint {
+                x: ShapeCoordinate::Pixels(0.0),
+                y: ShapeCoordinate::Pixels(0.0),
+            },
+            ShapePoint {
+                x: ShapeCoordinate::Pixels(20.0),
+                y: ShapeCoordinate::Pixels(40.0),
+            },
+        ])
+    );
+}
+
+#[test]
+fn parse_percentage_points() {
+    assert_eq!(
+        parse_points("50%,100%", 1.0),
+        Some(vec![ShapePoint {
+            x: ShapeCoordinate::Percentage(50.0),
+            y: ShapeCoordinate::Percentage(100.0),
+        }])
+    );
+    assert_eq!(ShapeCoordinate::Percentage(50.0).resolve(300.0), 150.0);
+}
+
+#[test]
+fn parse_invalid_points() {
+    assert_eq!(parse_points("0,0 10", 1.0), None);
+    assert_eq!(parse_points("a,b", 1.0), None);
+}
+
+#[test]
+fn parse_stroke_dashes() {
+    assert_eq!(parse_stroke_dash("5 3", 1.0), Some(vec![5.0, 3.0]));
+    assert_eq!(parse_stroke_dash("4", 2.0), Some(vec![8.0, 8.0]));
+    assert_eq!(parse_stroke_dash("5 x", 1.0), None);
+}
@@ -0,0 +1,58 @@
+use freya_node_state::{
+    parse_decoration, parse_decoration_style, parse_text_shadow, parse_text_shadows,
+};
+use skia_safe::{
+    textlayout::{TextDecoration, TextDecorationStyle, TextShadow},
+    Color,
+};
+
+#[test]
+fn parse_underline_decoration() {
+    let decoration = parse_decoration("underline");
+    assert_eq!(decoration, TextDecoration::UNDERLINE);
+}
+
+#[test]
+fn parse_multiple_decorations() {
+    let decoration = parse_decoration("underline line-through");
+    assert_eq!(
+        decoration,
+        TextDecoration::UNDERLINE | TextDecoration::LINE_THROUGH
+    );
+}
+
+#[test]
+fn parse_no_decoration() {
+    let decoration = parse_decoration("none");
+    assert_eq!(decoration, TextDecoration::NO_DECORATION);
+}
+
+#[test]
+fn parse_wavy_decoration_style() {
+    let style = parse_decoration_style("wavy");
+    assert_eq!(style, TextDecorationStyle::Wavy);
+}
+
+#[test]
+fn parse_simple_text_shadow() {
+    let shadow = parse_text_shadow("1 2 3 red", 1.0);
+    assert_eq!(shadow, Some(TextShadow::new(Color::RED, (1.0, 2.0), 3.0)));
+}
+
+#[test]
+fn parse_multiple_text_shadows() {
+    let shadows = parse_text_shadows("1 2 3 red, -1 -2 0 rgb(0, 0, 255)", 1.0);
+    assert_eq!(
+        shadows,
+        Some(vec![
+            TextShadow::new(Color::RED, (1.0, 2.0), 3.0),
+            TextShadow::new(Color::BLUE, (-1.0, -2.0), 0.0)
+        ])
+    );
+}
+
+#[test]
+fn parse_invalid_text_shadow() {
+    let shadows = parse_text_shadows("1 2 red", 1.0);
+    assert_eq!(shadows, None);
+}
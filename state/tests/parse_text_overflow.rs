@@ -0,0 +1,21 @@
+use freya_node_state::{parse_text_overflow, TextOverflow};
+
+#[test]
+fn parse_clip_text_overflow() {
+    let text_overflow = parse_text_overflow("clip");
+    assert_eq!(text_overflow, TextOverflow::Clip);
+}
+
+#[test]
+fn parse_ellipsis_text_overflow() {
+    let text_overflow = parse_text_overflow("ellipsis");
+    assert_eq!(text_overflow, TextOverflow::Ellipsis);
+    assert_eq!(text_overflow.get_ellipsis(), Some("…"));
+}
+
+#[test]
+fn parse_custom_text_overflow() {
+    let text_overflow = parse_text_overflow("->");
+    assert_eq!(text_overflow, TextOverflow::Custom("->".to_string()));
+    assert_eq!(text_overflow.get_ellipsis(), Some("->"));
+}
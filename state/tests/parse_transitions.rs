@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use freya_node_state::{
+    parse_layout_transition, parse_transitions, Easing, Transition, TransitionProperty,
+    TransitionValue,
+};
+use skia_safe::Color;
+use torin::prelude::{Area, Length, Point2D, Size, Size2D};
+
+#[test]
+fn parse_multiple_transitions() {
+    assert_eq!(
+        parse_transitions("background 200ms ease-out, width 0.15s"),
+        Some(vec![
+            Transition {
+                property: TransitionProperty::Background,
+                duration: Duration::from_millis(200),
+                easing: Easing::EaseOut,
+            },
+            Transition {
+                property: TransitionProperty::Width,
+                duration: Duration::from_millis(150),
+                easing: Easing::Linear,
+            },
+        ])
+    );
+}
+
+#[test]
+fn parse_invalid_transitions() {
+    assert_eq!(parse_transitions("background"), None);
+    assert_eq!(parse_transitions("color 200ms"), None);
+    assert_eq!(parse_transitions("width -5ms"), None);
+    assert_eq!(parse_transitions("width 5ms bounce"), None);
+}
+
+#[test]
+fn interpolate_transition_values() {
+    assert_eq!(
+        TransitionValue::Number(0.0).interpolate(&TransitionValue::Number(10.0), 0.5),
+        Some(TransitionValue::Number(5.0))
+    );
+    assert_eq!(
+        TransitionValue::Color(Color::from_rgb(0, 0, 0))
+            .interpolate(&TransitionValue::Color(Color::from_rgb(200, 100, 50)), 0.5),
+        Some(TransitionValue::Color(Color::from_rgb(100, 50, 25)))
+    );
+    assert_eq!(
+        TransitionValue::Size(Size::Pixels(Length::new(100.0))).interpolate(
+            &TransitionValue::Size(Size::Percentage(Length::new(50.0))),
+            0.5
+        ),
+        None
+    );
+}
+
+#[test]
+fn apply_easings() {
+    assert_eq!(Easing::Linear.apply(0.25), 0.25);
+    assert_eq!(Easing::EaseIn.apply(0.0), 0.0);
+    assert_eq!(Easing::EaseOut.apply(1.0), 1.0);
+    assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+}
+
+#[test]
+fn parse_layout_transitions() {
+    assert_eq!(
+        parse_layout_transition("300ms ease-in-out"),
+        Some(Transition {
+            property: TransitionProperty::Layout,
+            duration: Duration::from_millis(300),
+            easing: Easing::EaseInOut,
+        })
+    );
+    assert_eq!(parse_layout_transition("layout 300ms"), None);
+    assert_eq!(parse_layout_transition("300ms linear 2"), None);
+}
+
+#[test]
+fn interpolate_areas() {
+    let from = Area::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0));
+    let to = Area::new(Point2D::new(50.0, 100.0), Size2D::new(200.0, 50.0));
+    assert_eq!(
+        TransitionValue::Area(from).interpolate(&TransitionValue::Area(to), 0.5),
+        Some(TransitionValue::Area(Area::new(
+            Point2D::new(25.0, 50.0),
+            Size2D::new(150.0, 50.0)
+        )))
+    );
+}
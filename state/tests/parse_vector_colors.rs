@@ -0,0 +1,28 @@
+use freya_node_state::{parse_vector_color, VectorColor};
+use skia_safe::Color;
+
+#[test]
+fn parse_vector_colors() {
+    assert_eq!(
+        parse_vector_color("red"),
+        Some(VectorColor::Color(Color::RED))
+    );
+    assert_eq!(
+        parse_vector_color("rgb(10, 20, 30)"),
+        Some(VectorColor::Color(Color::from_rgb(10, 20, 30)))
+    );
+    assert_eq!(
+        parse_vector_color("currentColor"),
+        Some(VectorColor::CurrentColor)
+    );
+    assert_eq!(parse_vector_color("inherit"), None);
+}
+
+#[test]
+fn resolve_vector_colors() {
+    assert_eq!(VectorColor::CurrentColor.resolve(Color::BLUE), Color::BLUE);
+    assert_eq!(
+        VectorColor::Color(Color::RED).resolve(Color::BLUE),
+        Color::RED
+    );
+}
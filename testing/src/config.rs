@@ -1,11 +1,16 @@
+use std::path::PathBuf;
 use std::time::Duration;
 
+use freya_core::prelude::{CustomFont, DEFAULT_DOUBLE_CLICK_INTERVAL};
 use torin::geometry::Size2D;
 
 /// Configuration for a [`TestingHandler`].
 pub struct TestingConfig {
     vdom_timeout: Duration,
     size: Size2D,
+    fonts: Vec<CustomFont>,
+    default_font: &'static str,
+    double_click_interval: Duration,
 }
 
 impl Default for TestingConfig {
@@ -13,6 +18,9 @@ impl Default for TestingConfig {
         Self {
             vdom_timeout: Duration::from_millis(16),
             size: Size2D::from((500.0, 500.0)),
+            fonts: Vec::new(),
+            default_font: "Fira Sans",
+            double_click_interval: DEFAULT_DOUBLE_CLICK_INTERVAL,
         }
     }
 }
@@ -34,6 +42,30 @@ impl TestingConfig {
         self
     }
 
+    /// Register a font from its bytes under the given family name.
+    pub fn with_font(mut self, family: &str, bytes: &'static [u8]) -> Self {
+        self.fonts.push(CustomFont::from_bytes(family, bytes));
+        self
+    }
+
+    /// Register a font file under the given family name.
+    pub fn with_font_file(mut self, family: &str, path: impl Into<PathBuf>) -> Self {
+        self.fonts.push(CustomFont::from_file(family, path));
+        self
+    }
+
+    /// Specify the font family used by default, default is `Fira Sans`.
+    pub fn with_default_font(mut self, default_font: &'static str) -> Self {
+        self.default_font = default_font;
+        self
+    }
+
+    /// Specify the max time between two clicks for them to be a double click, default is 500ms.
+    pub fn with_double_click_interval(mut self, double_click_interval: Duration) -> Self {
+        self.double_click_interval = double_click_interval;
+        self
+    }
+
     /// Get the canvas size.
     pub fn size(&self) -> Size2D {
         self.size
@@ -43,4 +75,19 @@ impl TestingConfig {
     pub fn vdom_timeout(&self) -> Duration {
         self.vdom_timeout
     }
+
+    /// Get the custom fonts.
+    pub fn fonts(&self) -> &[CustomFont] {
+        &self.fonts
+    }
+
+    /// Get the default font family.
+    pub fn default_font(&self) -> &'static str {
+        self.default_font
+    }
+
+    /// Get the max time between two clicks for them to be a double click.
+    pub fn double_click_interval(&self) -> Duration {
+        self.double_click_interval
+    }
 }
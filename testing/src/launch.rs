@@ -2,11 +2,10 @@ use dioxus_core::{Component, VirtualDom};
 use freya_common::EventMessage;
 use freya_core::events::DomEvent;
 use freya_core::events::EventsProcessor;
+use freya_core::fonts::create_font_collection;
 use freya_dom::prelude::{FreyaDOM, SafeDOM};
 use freya_layout::Layers;
 use rustc_hash::FxHashMap;
-use skia_safe::textlayout::FontCollection;
-use skia_safe::FontMgr;
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc::unbounded_channel;
 
@@ -32,9 +31,9 @@ pub fn launch_test_with_config(root: Component<()>, config: TestingConfig) -> Te
     let (platform_event_emitter, platform_event_receiver) = unbounded_channel::<EventMessage>();
     let layers = Arc::new(Mutex::new(Layers::default()));
     let freya_events = Vec::new();
-    let events_processor = EventsProcessor::default();
-    let mut font_collection = FontCollection::new();
-    font_collection.set_dynamic_font_manager(FontMgr::default());
+    let events_processor =
+        EventsProcessor::default().with_double_click_interval(config.double_click_interval());
+    let font_collection = create_font_collection(config.fonts(), config.default_font());
 
     let mut handler = TestingHandler {
         vdom,
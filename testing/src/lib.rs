@@ -1,5 +1,6 @@
 pub use freya_core::events::FreyaEvent;
 pub use freya_elements::events::mouse::MouseButton;
+pub use freya_node_state::{AttributeDiagnostic, DiagnosticReason};
 
 mod config;
 mod launch;
@@ -1,9 +1,12 @@
 use dioxus_core::VirtualDom;
+use dioxus_native_core::NodeId;
 use freya_common::EventMessage;
 use freya_core::prelude::*;
+use freya_node_state::AttributeDiagnostic;
 use skia_safe::textlayout::FontCollection;
+use std::path::PathBuf;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
-use torin::geometry::{Area, Size2D};
+use torin::geometry::{Area, CursorPoint, Size2D};
 
 pub use freya_core::events::FreyaEvent;
 pub use freya_elements::events::mouse::MouseButton;
@@ -60,35 +63,37 @@ impl TestingHandler {
 
         self.provide_vdom_contexts();
 
-        let vdom = &mut self.vdom;
-
         // Handle platform events
         loop {
             let ev = self.platform_event_receiver.try_recv();
 
             if let Ok(ev) = ev {
-                #[allow(clippy::match_single_binding)]
-                match ev {
-                    _ => {}
+                if let EventMessage::FocusNode(focus_id) = ev {
+                    focus_reference(
+                        focus_id,
+                        &self.utils.sdom().get(),
+                        &mut self.events_processor,
+                        &self.event_emitter,
+                    );
                 }
             } else {
                 break;
             }
         }
 
-        // Handle virtual dom events
-        loop {
-            let ev = self.event_receiver.try_recv();
+        self.handle_vdom_events();
 
-            if let Ok(ev) = ev {
-                vdom.handle_event(&ev.name, ev.data.any(), ev.element_id, false);
-                vdom.process_events();
-            } else {
-                break;
-            }
+        // The listeners ran, so the focus can move unless they prevented it
+        let focus_changed = apply_pending_focus(
+            &self.utils.sdom().get(),
+            &mut self.events_processor,
+            &self.event_emitter,
+        );
+        if focus_changed {
+            self.handle_vdom_events();
         }
 
-        timeout(self.config.vdom_timeout(), vdom.wait_for_work())
+        timeout(self.config.vdom_timeout(), self.vdom.wait_for_work())
             .await
             .ok();
 
@@ -100,11 +105,33 @@ impl TestingHandler {
             .get_mut()
             .apply_mutations(mutations, SCALE_FACTOR as f32);
 
+        self.events_processor
+            .prune_removed_nodes(&self.utils.sdom().get());
+
         self.wait_for_work(self.config.size());
 
         (must_repaint, must_relayout)
     }
 
+    /// Run the listeners of the emitted events.
+    fn handle_vdom_events(&mut self) {
+        loop {
+            let ev = self.event_receiver.try_recv();
+
+            if let Ok(ev) = ev {
+                // Skip the listeners of events stopped by a previous listener
+                if ev.is_propagation_stopped() {
+                    continue;
+                }
+                self.vdom
+                    .handle_event(&ev.name, ev.data.any(), ev.element_id, false);
+                self.vdom.process_events();
+            } else {
+                break;
+            }
+        }
+    }
+
     /// Wait for layout and events to be processed
     pub fn wait_for_work(&mut self, size: Size2D) {
         // Clear cached results
@@ -140,6 +167,55 @@ impl TestingHandler {
         self.events_queue.push(event);
     }
 
+    /// Simulate a file from the OS being dragged over the window, which emits a `filehover` event.
+    pub fn hover_file(&mut self, cursor: CursorPoint, file_path: impl Into<PathBuf>) {
+        self.push_event(FreyaEvent::File {
+            name: "filehover".to_string(),
+            cursor,
+            file_path: Some(file_path.into()),
+        });
+    }
+
+    /// Simulate a file from the OS being dropped in the window, which emits a `filedrop` event.
+    pub fn drop_file(&mut self, cursor: CursorPoint, file_path: impl Into<PathBuf>) {
+        self.push_event(FreyaEvent::File {
+            name: "filedrop".to_string(),
+            cursor,
+            file_path: Some(file_path.into()),
+        });
+    }
+
+    /// Simulate the dragged files leaving the window without being dropped,
+    /// which emits a `filehovercancel` event to the elements that received the `filehover`.
+    pub fn cancel_file_hover(&mut self, cursor: CursorPoint) {
+        self.push_event(FreyaEvent::File {
+            name: "filehovercancel".to_string(),
+            cursor,
+            file_path: None,
+        });
+    }
+
+    /// Get the problems found while applying the attributes of the Nodes,
+    /// e.g: unsupported attributes or values that could not be parsed.
+    pub fn diagnostics(&self) -> Vec<AttributeDiagnostic> {
+        self.utils.sdom().get().diagnostics().get()
+    }
+
+    /// Get the [`InteractionState`] of a node, used to resolve styles like `hover_background`.
+    pub fn interaction_state(&self, node_id: NodeId) -> InteractionState {
+        self.events_processor.interaction_states().get(&node_id)
+    }
+
+    /// Get the node that has the keyboard focus.
+    pub fn focused_node(&self) -> Option<NodeId> {
+        self.events_processor.focused_node()
+    }
+
+    /// Get the node that is capturing the pointer.
+    pub fn pointer_capture(&self) -> Option<NodeId> {
+        self.events_processor.pointer_capture()
+    }
+
     /// Get the root node
     pub fn root(&mut self) -> TestNode {
         let root_id = {
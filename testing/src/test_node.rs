@@ -36,6 +36,11 @@ impl TestNode {
         Some(child)
     }
 
+    /// Get the Node ID
+    pub fn id(&self) -> NodeId {
+        self.node_id
+    }
+
     /// Get the Node text
     pub fn text(&self) -> Option<&str> {
         if let NodeType::Text(TextNode { text, .. }) = &self.node_type {
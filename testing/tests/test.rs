@@ -1,8 +1,13 @@
+use std::sync::Arc;
+
 use dioxus::prelude::*;
 use freya_core::events::FreyaEvent;
 use freya_elements::elements as dioxus_elements;
 use freya_elements::events::mouse::MouseButton;
-use freya_testing::launch_test;
+use freya_node_state::{CustomAttributeValues, Stylesheet};
+use freya_testing::{
+    launch_test, launch_test_with_config, AttributeDiagnostic, DiagnosticReason, TestingConfig,
+};
 
 #[tokio::test]
 async fn no_state() {
@@ -111,3 +116,243 @@ async fn simulate_events() {
 
     assert_eq!(text.text(), Some("Is enabled? true"));
 }
+
+#[tokio::test]
+async fn stylesheet_classes() {
+    fn stylesheet_app(cx: Scope) -> Element {
+        let stylesheet = Stylesheet::new()
+            .with_rule("rect", &[("height", "100")])
+            .with_rule(".half", &[("width", "50%")])
+            .with_rule("rect.small", &[("width", "20")]);
+
+        render!(
+            rect {
+                stylesheet: cx.any_value(CustomAttributeValues::Stylesheet(Arc::new(stylesheet))),
+                width: "100%",
+                height: "100%",
+                rect {
+                    class: "half",
+                }
+                rect {
+                    class: "half small",
+                }
+                rect {
+                    class: "half",
+                    width: "10",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(stylesheet_app);
+
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // Attributes from the matched rules
+    assert_eq!(root.get(0).layout().unwrap().width(), 250.0);
+    assert_eq!(root.get(0).layout().unwrap().height(), 100.0);
+
+    // More specific rules override the less specific ones
+    assert_eq!(root.get(1).layout().unwrap().width(), 20.0);
+
+    // Inline attributes override the stylesheet
+    assert_eq!(root.get(2).layout().unwrap().width(), 10.0);
+}
+
+#[tokio::test]
+async fn attribute_diagnostics() {
+    fn diagnostics_app(cx: Scope) -> Element {
+        let valid = use_state(cx, || false);
+
+        let (width, radius) = if *valid.get() {
+            ("100", "5")
+        } else {
+            ("abc", "five")
+        };
+
+        render!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: |_| valid.set(true),
+                rect {
+                    width: "{width}",
+                    radius: "{radius}",
+                    background: "inherit",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(diagnostics_app);
+
+    utils.wait_for_update().await;
+
+    let inner_rect_id = utils.root().get(0).get(0).id();
+    let mut diagnostics = utils.diagnostics();
+    diagnostics.sort_by(|a, b| a.attribute.cmp(&b.attribute));
+
+    assert_eq!(
+        diagnostics,
+        vec![
+            AttributeDiagnostic {
+                node_id: inner_rect_id,
+                attribute: "radius".to_string(),
+                value: "five".to_string(),
+                reason: DiagnosticReason::InvalidValue,
+            },
+            AttributeDiagnostic {
+                node_id: inner_rect_id,
+                attribute: "width".to_string(),
+                value: "abc".to_string(),
+                reason: DiagnosticReason::InvalidValue,
+            }
+        ]
+    );
+
+    utils.push_event(FreyaEvent::Mouse {
+        name: "click".to_string(),
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    // Fixing the values clears the diagnostics
+    assert!(utils.diagnostics().is_empty());
+}
+
+#[tokio::test]
+async fn unsupported_attribute_diagnostics() {
+    fn unsupported_app(cx: Scope) -> Element {
+        let stylesheet = Stylesheet::new().with_rule("label", &[("radius", "5"), ("color", "red")]);
+
+        render!(
+            rect {
+                stylesheet: cx.any_value(CustomAttributeValues::Stylesheet(Arc::new(stylesheet))),
+                label {
+                    "Hello"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(unsupported_app);
+
+    utils.wait_for_update().await;
+
+    let label_id = utils.root().get(0).get(0).id();
+
+    // Labels have no radius
+    assert_eq!(
+        utils.diagnostics(),
+        vec![AttributeDiagnostic {
+            node_id: label_id,
+            attribute: "radius".to_string(),
+            value: "5".to_string(),
+            reason: DiagnosticReason::UnsupportedAttribute,
+        }]
+    );
+}
+
+static MONO_FONT: &[u8] = include_bytes!("./fonts/DejaVuSansMono.ttf");
+
+/// Get the widths of the texts of the labels inside the first element.
+fn label_widths(utils: &mut freya_testing::TestingHandler) -> Vec<f32> {
+    let root = utils.root().get(0);
+    (0..2)
+        .map(|i| root.get(i).get(0).layout().unwrap().width())
+        .collect()
+}
+
+#[tokio::test]
+async fn custom_font_family() {
+    fn font_app(cx: Scope) -> Element {
+        render!(
+            rect {
+                label {
+                    font_family: "Mono",
+                    "iiii"
+                }
+                label {
+                    font_family: "Mono",
+                    "WWWW"
+                }
+            }
+        )
+    }
+
+    let mut utils =
+        launch_test_with_config(font_app, TestingConfig::new().with_font("Mono", MONO_FONT));
+
+    utils.wait_for_update().await;
+
+    // Every glyph of the monospace font has the same width
+    let widths = label_widths(&mut utils);
+    assert!(widths[0] > 0.0);
+    assert_eq!(widths[0], widths[1]);
+}
+
+#[tokio::test]
+async fn custom_default_font() {
+    fn font_app(cx: Scope) -> Element {
+        render!(
+            rect {
+                label {
+                    "iiii"
+                }
+                label {
+                    "WWWW"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        font_app,
+        TestingConfig::new()
+            .with_font("Mono", MONO_FONT)
+            .with_default_font("Mono"),
+    );
+
+    utils.wait_for_update().await;
+
+    // Texts without a font family use the default one
+    let widths = label_widths(&mut utils);
+    assert!(widths[0] > 0.0);
+    assert_eq!(widths[0], widths[1]);
+}
+
+#[tokio::test]
+async fn missing_font_file() {
+    fn font_app(cx: Scope) -> Element {
+        render!(
+            rect {
+                label {
+                    font_family: "Missing, Mono",
+                    "iiii"
+                }
+                label {
+                    font_family: "Missing, Mono",
+                    "WWWW"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        font_app,
+        TestingConfig::new()
+            .with_font_file("Missing", "./tests/fonts/Missing.ttf")
+            .with_font("Mono", MONO_FONT),
+    );
+
+    utils.wait_for_update().await;
+
+    // The missing font is skipped and the next family is used instead
+    let widths = label_widths(&mut utils);
+    assert!(widths[0] > 0.0);
+    assert_eq!(widths[0], widths[1]);
+}